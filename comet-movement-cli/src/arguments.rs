@@ -0,0 +1,384 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use comet_movement_core::util::{
+    physics::integrators::IntegrationMethod,
+    time::checked_julian_date,
+};
+
+/// Справка по запуску
+pub const USAGE: &str = "\
+Usage: comet-movement-cli (--ticks N | --until DATE) [OPTIONS]
+
+Runs the simulation without a window and writes the state of every body
+to CSV: time, julian_date, body, x, y, vx, vy, distance_to_sun
+(seconds, km and km/s).
+
+Options:
+  --ticks N               Number of ticks to simulate
+  --until DATE            Simulate until a date: YYYY-MM-DD (astronomical
+                          years, -YYYY-MM-DD before year 1) or a Julian date,
+                          dates before the start are reached backwards in time
+  --seconds-per-tick S    Simulated seconds per tick, negative to run
+                          backwards in time (default: SECONDS_PER_TICK)
+  --method METHOD         average_velocity, velocity_verlet, leapfrog or rk4
+                          (default: INTEGRATION_METHOD; backwards runs
+                          replace average_velocity and rk4 by velocity_verlet)
+  --every K               Write every K-th tick (default: 1)
+  --comets N              Add N random comets before the run (default: 0)
+  --spawn-profile NAME    Spawn the comets from a profile of the comet section
+                          of the solar system data (default: uniform random)
+  --seed SEED             Seed of the random generator (default: SEED,
+                          random if empty; the used seed is printed to stderr)
+  --output PATH           Output file (default: standard output)
+  --help                  Show this help
+
+Other settings are read from .env in the working directory.";
+
+/// Когда остановить симуляцию
+pub enum Duration {
+    /// После заданного количества тиков
+    Ticks(u64),
+    /// По достижении юлианской даты
+    Until(f64),
+}
+
+/// Аргументы командной строки
+pub struct Arguments {
+    /// Продолжительность симуляции
+    pub duration: Duration,
+    /// Секунд за тик
+    pub seconds_per_tick: Option<f64>,
+    /// Метод численного интегрирования
+    pub integration_method: Option<IntegrationMethod>,
+    /// Запись каждого K-го тика
+    pub every: u64,
+    /// Количество случайных комет
+    pub comets: u16,
+    /// Название профиля появления комет
+    pub spawn_profile: Option<String>,
+    /// Зерно генератора случайных чисел
+    pub seed: Option<u64>,
+    /// Файл для записи
+    pub output: Option<String>,
+}
+
+impl Arguments {
+    /// Разбор аргументов, `Ok(None)` при запросе справки
+    pub fn parse(
+        mut arguments: impl Iterator<Item = String>,
+    ) -> Result<Option<Self>, ArgumentsError> {
+        let mut duration = None;
+        let mut seconds_per_tick = None;
+        let mut integration_method = None;
+        let mut every = 1;
+        let mut comets = 0;
+        let mut spawn_profile = None;
+        let mut seed = None;
+        let mut output = None;
+
+        while let Some(argument) = arguments.next() {
+            let mut value = || {
+                arguments.next().ok_or_else(|| {
+                    ArgumentsError::MissingValue(
+                        argument.clone(),
+                    )
+                })
+            };
+
+            match argument.as_str() {
+                "--help" | "-h" => return Ok(None),
+                "--ticks" => {
+                    duration = Some(Duration::Ticks(parse(
+                        &argument,
+                        value()?,
+                    )?))
+                }
+                "--until" => {
+                    duration = Some(Duration::Until(
+                        parse_date(&value()?).ok_or_else(
+                            || {
+                                ArgumentsError::InvalidValue(
+                                    argument.clone(),
+                                )
+                            },
+                        )?,
+                    ))
+                }
+                "--seconds-per-tick" => {
+                    seconds_per_tick =
+                        Some(parse(&argument, value()?)?)
+                }
+                "--method" => {
+                    integration_method =
+                        Some(parse(&argument, value()?)?)
+                }
+                "--every" => every = parse(&argument, value()?)?,
+                "--comets" => {
+                    comets = parse(&argument, value()?)?
+                }
+                "--spawn-profile" => {
+                    spawn_profile = Some(value()?)
+                }
+                "--seed" => {
+                    seed = Some(parse(&argument, value()?)?)
+                }
+                "--output" => output = Some(value()?),
+                _ => {
+                    return Err(ArgumentsError::Unknown(
+                        argument,
+                    ));
+                }
+            }
+        }
+
+        if every == 0 {
+            return Err(ArgumentsError::InvalidValue(
+                "--every".into(),
+            ));
+        }
+
+        Ok(Some(Self {
+            duration: duration
+                .ok_or(ArgumentsError::MissingDuration)?,
+            seconds_per_tick,
+            integration_method,
+            every,
+            comets,
+            spawn_profile,
+            seed,
+            output,
+        }))
+    }
+}
+
+/// Разбор значения аргумента
+fn parse<T: FromStr>(
+    argument: &str,
+    value: String,
+) -> Result<T, ArgumentsError> {
+    value.parse().map_err(|_| {
+        ArgumentsError::InvalidValue(argument.into())
+    })
+}
+
+/// Разбор даты: `YYYY-MM-DD` или юлианская дата.
+/// Годы астрономические, до 1 года со знаком минус
+fn parse_date(value: &str) -> Option<f64> {
+    if let Ok(julian_date) = value.parse::<f64>() {
+        return julian_date
+            .is_finite()
+            .then_some(julian_date);
+    }
+
+    let (sign, date) = match value.strip_prefix('-') {
+        Some(date) => (-1, date),
+        None => (1, value),
+    };
+
+    let mut parts = date.splitn(3, '-');
+    let year = parts.next()?.parse::<i32>().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+
+    checked_julian_date(sign * year, month, day)
+}
+
+/// Ошибка разбора аргументов
+pub enum ArgumentsError {
+    /// Неизвестный аргумент
+    Unknown(String),
+    /// У аргумента нет значения
+    MissingValue(String),
+    /// Некорректное значение аргумента
+    InvalidValue(String),
+    /// Не задана продолжительность симуляции
+    MissingDuration,
+}
+
+impl Display for ArgumentsError {
+    fn fmt(
+        &self,
+        fmt: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Self::Unknown(argument) => {
+                write!(fmt, "unknown argument {argument}")
+            }
+            Self::MissingValue(argument) => {
+                write!(fmt, "{argument} requires a value")
+            }
+            Self::InvalidValue(argument) => {
+                write!(fmt, "invalid value for {argument}")
+            }
+            Self::MissingDuration => {
+                write!(
+                    fmt,
+                    "either --ticks or --until is required"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use comet_movement_core::util::time::{
+        J2000_JULIAN_DATE, julian_date,
+    };
+
+    use super::*;
+
+    /// Разбор аргументов из строки
+    fn parse_arguments(
+        line: &str,
+    ) -> Result<Option<Arguments>, ArgumentsError> {
+        Arguments::parse(
+            line.split_whitespace().map(String::from),
+        )
+    }
+
+    /// Юлианская дата остановки из аргументов
+    fn until(line: &str) -> Option<f64> {
+        match parse_arguments(line) {
+            Ok(Some(Arguments {
+                duration: Duration::Until(julian_date),
+                ..
+            })) => Some(julian_date),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn parses_all_options() {
+        let arguments = parse_arguments(
+            "--ticks 100 --seconds-per-tick -30 \
+             --method leapfrog --every 10 --comets 5 \
+             --spawn-profile oort --seed 42 --output out.csv",
+        )
+        .ok()
+        .flatten()
+        .unwrap();
+
+        assert!(matches!(
+            arguments.duration,
+            Duration::Ticks(100)
+        ));
+        assert_eq!(arguments.seconds_per_tick, Some(-30.));
+        assert!(matches!(
+            arguments.integration_method,
+            Some(IntegrationMethod::Leapfrog)
+        ));
+        assert_eq!(arguments.every, 10);
+        assert_eq!(arguments.comets, 5);
+        assert_eq!(
+            arguments.spawn_profile.as_deref(),
+            Some("oort")
+        );
+        assert_eq!(arguments.seed, Some(42));
+        assert_eq!(
+            arguments.output.as_deref(),
+            Some("out.csv")
+        );
+    }
+
+    #[test]
+    fn uses_defaults() {
+        let arguments = parse_arguments("--ticks 1")
+            .ok()
+            .flatten()
+            .unwrap();
+
+        assert!(arguments.seconds_per_tick.is_none());
+        assert!(arguments.integration_method.is_none());
+        assert_eq!(arguments.every, 1);
+        assert_eq!(arguments.comets, 0);
+        assert!(arguments.seed.is_none());
+        assert!(arguments.output.is_none());
+    }
+
+    #[test]
+    fn requests_help() {
+        assert!(matches!(
+            parse_arguments("--ticks 1 --help"),
+            Ok(None)
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        assert!(matches!(
+            parse_arguments("--seed 1"),
+            Err(ArgumentsError::MissingDuration)
+        ));
+        assert!(matches!(
+            parse_arguments("--ticks"),
+            Err(ArgumentsError::MissingValue(_))
+        ));
+        assert!(matches!(
+            parse_arguments("--ticks 1 --speed 2"),
+            Err(ArgumentsError::Unknown(_))
+        ));
+        assert!(matches!(
+            parse_arguments("--ticks -1"),
+            Err(ArgumentsError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            parse_arguments("--ticks 1 --every 0"),
+            Err(ArgumentsError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            parse_arguments("--ticks 1 --method euler"),
+            Err(ArgumentsError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn parses_dates() {
+        assert_eq!(
+            until("--until 2000-01-01"),
+            Some(J2000_JULIAN_DATE - 0.5)
+        );
+        assert_eq!(
+            until("--until 2024-02-29"),
+            Some(julian_date(2024, 2, 29.))
+        );
+        assert_eq!(
+            until("--until 2451545.0"),
+            Some(J2000_JULIAN_DATE)
+        );
+        // 15.03.44 до н. э.
+        assert_eq!(
+            until("--until -43-03-15"),
+            Some(julian_date(-43, 3, 15.))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        for date in [
+            "2024-02-31",
+            "2023-02-29",
+            "2024-13-01",
+            "2024-00-10",
+            "2024-01-00",
+            "2024-01",
+            "2024-01-01-01",
+            "NaN",
+            "inf",
+            "--2024-01-01",
+        ] {
+            assert!(
+                matches!(
+                    parse_arguments(&format!(
+                        "--until {date}"
+                    )),
+                    Err(ArgumentsError::InvalidValue(_))
+                ),
+                "{date} must be rejected"
+            );
+        }
+    }
+}
@@ -0,0 +1,269 @@
+use std::{
+    env::args,
+    fs::File,
+    io::{BufWriter, Write, stdout},
+    process::ExitCode,
+};
+
+use comet_movement_core::{
+    config::Config,
+    space::SpaceState,
+    util::{
+        objects::{MovingObject, Object},
+        physics::quantities::{
+            Quantity, quantity_units::Seconds,
+        },
+    },
+};
+use rand::{SeedableRng, rngs::ChaCha12Rng};
+
+use crate::arguments::{Arguments, Duration, USAGE};
+
+mod arguments;
+
+/// Секунд в сутках
+const SECONDS_PER_DAY: f64 = 86_400.;
+
+fn main() -> ExitCode {
+    let arguments = match Arguments::parse(args().skip(1)) {
+        Ok(Some(arguments)) => arguments,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(arguments) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Запуск симуляции с записью состояний в CSV
+fn run(arguments: Arguments) -> Result<(), String> {
+    let config = Config::from_env()
+        .map_err(|error| error.to_string())?;
+
+    let mut seconds_per_tick = arguments
+        .seconds_per_tick
+        .map(|seconds| Quantity::new(Seconds::new(seconds)))
+        .unwrap_or(config.seconds_per_tick());
+
+    if seconds_per_tick.value() == 0. {
+        return Err(
+            "seconds per tick must not be zero".into()
+        );
+    }
+
+    // Количество тиков и время от начала до остановки
+    let (ticks, end_time) = match arguments.duration {
+        Duration::Ticks(ticks) => {
+            (ticks, ticks as f64 * seconds_per_tick.value())
+        }
+        Duration::Until(julian_date) => {
+            let seconds = (julian_date
+                - config.start_julian_date())
+                * SECONDS_PER_DAY;
+
+            // К дате до начала симуляции время идёт назад
+            seconds_per_tick = Quantity::new(Seconds::new(
+                seconds_per_tick
+                    .value()
+                    .abs()
+                    .copysign(seconds),
+            ));
+
+            // Последний тик короче, чтобы остановиться точно на дате
+            let ticks = (seconds / seconds_per_tick.value())
+                .ceil() as u64;
+
+            (ticks, seconds)
+        }
+    };
+
+    let integration_method = arguments
+        .integration_method
+        .unwrap_or(config.integration_method());
+
+    // Назад во времени интегрирует только обратимый метод
+    let integration_method =
+        if seconds_per_tick.value() < 0. {
+            integration_method.time_reversible()
+        } else {
+            integration_method
+        };
+
+    let output: Box<dyn Write> = match &arguments.output {
+        Some(path) => Box::new(
+            File::create(path).map_err(|error| {
+                format!("can not create {path}: {error}")
+            })?,
+        ),
+        None => Box::new(stdout().lock()),
+    };
+    let mut output = BufWriter::new(output);
+
+    // Зерно выводится, чтобы запуск можно было повторить
+    let seed = arguments.seed.unwrap_or(config.seed());
+    eprintln!("seed: {seed}");
+    let mut rng = ChaCha12Rng::seed_from_u64(seed);
+
+    let mut space = SpaceState::new(&config, &mut rng)
+        .map_err(|error| error.to_string())?;
+    // Траектории нужны только для отрисовки
+    space.set_recording_trajectories(false);
+
+    // Кометы, которые не удалось добавить, пропускаются
+    let spawn_results = match &arguments.spawn_profile {
+        Some(name) => {
+            let spawn_profiles = space
+                .comet_possible_values()
+                .spawn_profiles();
+
+            let index = spawn_profiles
+                .iter()
+                .position(|profile| {
+                    profile.name().as_ref() == name
+                })
+                .ok_or_else(|| {
+                    format!(
+                        "unknown spawn profile {name}, available: {}",
+                        spawn_profiles
+                            .iter()
+                            .map(|profile| profile
+                                .name()
+                                .as_ref())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                })?;
+
+            (0..arguments.comets)
+                .map(|_| {
+                    space.add_new_comet_from_profile(
+                        index, &mut rng,
+                    )
+                })
+                .collect::<Vec<_>>()
+        }
+        None => (0..arguments.comets)
+            .map(|_| space.add_new_comet(&mut rng))
+            .collect(),
+    };
+    spawn_results
+        .into_iter()
+        .filter_map(Result::err)
+        .for_each(|error| {
+            eprintln!("warning: comet not added: {error}")
+        });
+
+    let write_error = |error: std::io::Error| {
+        format!("can not write: {error}")
+    };
+
+    writeln!(
+        output,
+        "time,julian_date,body,x,y,vx,vy,distance_to_sun"
+    )
+    .map_err(write_error)?;
+
+    let mut previous_time = 0.;
+    for tick in 0..=ticks {
+        let time = if tick == ticks {
+            end_time
+        } else {
+            tick as f64 * seconds_per_tick.value()
+        };
+
+        if tick > 0 {
+            space.move_objects(
+                Quantity::new(Seconds::new(
+                    time - previous_time,
+                )),
+                integration_method.integrator(),
+                config.step_control(),
+            );
+            space.remove_crashed_comets();
+        }
+        previous_time = time;
+
+        if tick % arguments.every == 0 || tick == ticks {
+            // Сложение с нулём убирает отрицательный ноль
+            let time = time + 0.;
+
+            write_states(
+                &mut output,
+                &space,
+                time,
+                config.start_julian_date()
+                    + time / SECONDS_PER_DAY,
+            )
+            .map_err(write_error)?;
+        }
+    }
+
+    output.flush().map_err(write_error)
+}
+
+/// Запись состояний всех объектов на момент времени
+fn write_states(
+    output: &mut impl Write,
+    space: &SpaceState,
+    time: f64,
+    julian_date: f64,
+) -> std::io::Result<()> {
+    let sun = space.sun().borrow();
+    let sun_position = sun.position();
+
+    let mut write_state =
+        |object: &dyn MovingObject| -> std::io::Result<()> {
+            let position = object.position();
+            let (x, y) =
+                (position.x.value(), position.y.value());
+            let velocity =
+                object.movement().velocity().to_vector();
+
+            writeln!(
+                output,
+                "{time},{julian_date},{},{x},{y},{},{},{}",
+                csv_field(object.name()),
+                velocity.x,
+                velocity.y,
+                (x - sun_position.x.value())
+                    .hypot(y - sun_position.y.value()),
+            )
+        };
+
+    write_state(&*sun)?;
+
+    for planet in space.planets() {
+        let planet = planet.borrow();
+        write_state(&*planet)?;
+
+        for satellite in planet.satellites() {
+            write_state(&*satellite.borrow())?;
+        }
+    }
+
+    for comet in space.comets() {
+        write_state(&*comet.borrow())?;
+    }
+
+    Ok(())
+}
+
+/// Экранирование поля CSV
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
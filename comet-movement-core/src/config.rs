@@ -0,0 +1,237 @@
+use gset::Getset;
+
+use crate::util::{
+    data::config_data::{ConfigData, ConfigError},
+    physics::{
+        integrators::{
+            IntegrationMethod, step_control::StepControl,
+        },
+        quantities::{Quantity, quantity_units::Seconds},
+    },
+};
+
+/// Данные из файла конфигурации
+#[derive(Getset, Clone)]
+pub struct Config {
+    /// Секунд проходит каждый тик
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    seconds_per_tick: Quantity<Seconds>,
+    /// Базовое значение изменения масштаба
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    base_scale_change_factor: u32,
+    /// Путь к файлу с данными Солнечной системы
+    #[getset(get, vis = "pub", ty = "&str")]
+    #[getset(set, vis = "pub")]
+    path_to_solar_system_values: String,
+    /// Значение для изменения количества точек для отрисовки при уменьшении
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    step_formation: u32,
+    /// Начальная скорость симуляции в сутках за секунду реального времени
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    default_days_per_second: f64,
+    /// Время в миллисекундах на один кадр: шаги интегрирования и отрисовку
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    frame_budget_in_millis: u16,
+    /// Начальное значение масштаба
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    default_scale: u32,
+    /// Количество фоновых звёзд
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    background_stars_count: u16,
+    /// Путь к папке с изображениями
+    #[getset(get, vis = "pub", ty = "&str")]
+    #[getset(set, vis = "pub")]
+    path_to_assets: String,
+    /// Максимальное количество комет, без него кометы не ограничены
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    maximum_number_of_comets: Option<u32>,
+    /// Являются ли кометы пробными частицами, которые не притягивают
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    are_comets_test_particles: bool,
+    /// Метод численного интегрирования по умолчанию
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    integration_method: IntegrationMethod,
+    /// Двигается ли Солнце
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    is_sun_moving: bool,
+    /// Юлианская дата начала симуляции
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    start_julian_date: f64,
+    /// Путь к папке с таблицами векторов JPL Horizons
+    #[getset(set, vis = "pub")]
+    path_to_horizons_vectors: Option<String>,
+    /// Путь к файлу элементов орбит комет Центра малых планет
+    #[getset(set, vis = "pub")]
+    path_to_comet_elements: Option<String>,
+    /// Обозначения импортируемых комет
+    #[getset(set, vis = "pub")]
+    imported_comets: Vec<Box<str>>,
+    /// Управление шагом интегрирования
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    step_control: StepControl,
+    /// Зерно генератора случайных чисел
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    seed: u64,
+    /// Путь к файлу снимка симуляции
+    #[getset(get, vis = "pub", ty = "&str")]
+    #[getset(set, vis = "pub")]
+    path_to_snapshot: String,
+    /// Количество кадров для перемотки
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    rewind_capacity: u16,
+    /// Количество тиков между кадрами перемотки
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    ticks_between_rewind_frames: u32,
+}
+
+impl Config {
+    /// Чтение конфига из переменных окружения и файла `.env`
+    pub fn from_env() -> Result<Self, ConfigError> {
+        ConfigData::init();
+        Ok(Self {
+            seconds_per_tick: Quantity::new(Seconds::new(
+                ConfigData::get("SECONDS_PER_TICK")?,
+            )),
+            base_scale_change_factor: ConfigData::get(
+                "BASE_SCALE_CHANGE_FACTOR",
+            )?,
+            path_to_solar_system_values: ConfigData::get(
+                "PATH_TO_SOLAR_SYSTEM_VALUES",
+            )?,
+            step_formation: ConfigData::get(
+                "STEP_FORMATION",
+            )?,
+            default_days_per_second: ConfigData::get(
+                "DEFAULT_DAYS_PER_SECOND",
+            )?,
+            frame_budget_in_millis: ConfigData::get(
+                "FRAME_BUDGET_IN_MILLIS",
+            )?,
+            default_scale: ConfigData::get(
+                "DEFAULT_SCALE",
+            )?,
+            background_stars_count: ConfigData::get(
+                "BACKGROUND_STARS_COUNT",
+            )?,
+            path_to_assets: ConfigData::get(
+                "PATH_TO_ASSETS",
+            )?,
+            maximum_number_of_comets:
+                ConfigData::get_optional(
+                    "MAXIMUM_NUMBER_OF_COMETS",
+                )?,
+            are_comets_test_particles: ConfigData::get(
+                "ARE_COMETS_TEST_PARTICLES",
+            )?,
+            integration_method: ConfigData::get(
+                "INTEGRATION_METHOD",
+            )?,
+            is_sun_moving: ConfigData::get(
+                "IS_SUN_MOVING",
+            )?,
+            start_julian_date: ConfigData::get(
+                "START_JULIAN_DATE",
+            )?,
+            path_to_horizons_vectors:
+                ConfigData::get_optional(
+                    "PATH_TO_HORIZONS_VECTORS",
+                )?,
+            path_to_comet_elements:
+                ConfigData::get_optional(
+                    "PATH_TO_COMET_ELEMENTS",
+                )?,
+            imported_comets: ConfigData::get_list(
+                "IMPORTED_COMETS",
+            ),
+            step_control: StepControl::new(
+                ConfigData::get("TIME_STEP_ACCURACY")?,
+                ConfigData::get(
+                    "MAXIMUM_SUBSTEPS_PER_TICK",
+                )?,
+            ),
+            // Случайное зерно, если в конфиге не задано
+            seed: ConfigData::get_optional("SEED")?
+                .unwrap_or_else(rand::random),
+            path_to_snapshot: ConfigData::get(
+                "PATH_TO_SNAPSHOT",
+            )?,
+            rewind_capacity: ConfigData::get(
+                "REWIND_CAPACITY",
+            )?,
+            ticks_between_rewind_frames: ConfigData::get(
+                "TICKS_BETWEEN_REWIND_FRAMES",
+            )?,
+        })
+    }
+}
+
+/// Значения по умолчанию совпадают с `.env` из репозитория,
+/// зерно генератора случайное
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            seconds_per_tick: Quantity::new(Seconds::new(
+                60.,
+            )),
+            base_scale_change_factor: 2000,
+            path_to_solar_system_values:
+                "solar_system_data.json".to_owned(),
+            step_formation: 18000,
+            default_days_per_second: 2.,
+            frame_budget_in_millis: 16,
+            default_scale: 150_000,
+            background_stars_count: 1000,
+            path_to_assets: "assets".to_owned(),
+            maximum_number_of_comets: None,
+            are_comets_test_particles: true,
+            integration_method:
+                IntegrationMethod::VelocityVerlet,
+            is_sun_moving: false,
+            start_julian_date: 2_451_545.,
+            path_to_horizons_vectors: None,
+            path_to_comet_elements: None,
+            imported_comets: Vec::new(),
+            step_control: StepControl::new(0.01, 1000),
+            seed: rand::random(),
+            path_to_snapshot: "snapshot.json".to_owned(),
+            rewind_capacity: 200,
+            ticks_between_rewind_frames: 500,
+        }
+    }
+}
+
+impl Config {
+    #[inline(always)]
+    pub fn path_to_horizons_vectors(&self) -> Option<&str> {
+        self.path_to_horizons_vectors.as_deref()
+    }
+
+    #[inline(always)]
+    pub fn path_to_comet_elements(&self) -> Option<&str> {
+        self.path_to_comet_elements.as_deref()
+    }
+
+    #[inline(always)]
+    pub fn imported_comets(&self) -> Vec<&str> {
+        self.imported_comets
+            .iter()
+            .map(AsRef::as_ref)
+            .collect()
+    }
+}
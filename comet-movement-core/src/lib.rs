@@ -0,0 +1,4 @@
+pub mod config;
+pub mod objects;
+pub mod space;
+pub mod util;
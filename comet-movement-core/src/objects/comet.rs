@@ -0,0 +1,391 @@
+use gset::Getset;
+use rand::{RngExt, rngs::ChaCha12Rng};
+
+use crate::{
+    objects::spawn_profile::{
+        SpawnProfile, SpawnProfileError,
+    },
+    util::{
+        color::Color,
+        data::{
+            snapshot::BodySnapshot,
+            solar_system_data::CometData,
+        },
+        geometry::{point::Point, vector::Vector},
+        objects::{
+            Object, ObjectMotion, id::ObjectId,
+            movement::ObjectMovement,
+        },
+        physics::{
+            orbit::{PerihelionPassage, StateVectors},
+            quantities::{
+                Quantity,
+                quantity_units::{
+                    Kilograms, Kilometers,
+                    KilometersPerSecond,
+                },
+            },
+            vector::VectorValue,
+        },
+    },
+};
+
+/// Комета
+pub struct Comet {
+    /// Идентификатор
+    id: ObjectId,
+    /// Название
+    name: Box<str>,
+    /// Движение
+    movement: ObjectMovement,
+    /// Масса
+    mass: Quantity<Kilograms>,
+    /// Радиус
+    radius: Quantity<Kilometers>,
+    /// Путь к изображению
+    image_path: Box<str>,
+    /// Пробная ли частица
+    is_test_particle: bool,
+}
+
+impl Comet {
+    /// Генерация случайного начального вектора состояния
+    pub fn generate_initial_state(
+        comet_possible_values: &CometPossibleValues,
+        rng: &mut ChaCha12Rng,
+    ) -> StateVectors {
+        // Генерация начальной скорости
+        let starting_velocity =
+            Quantity::new(KilometersPerSecond::new(
+                Self::generate_starting_velocity(
+                    comet_possible_values.velocities(),
+                    rng,
+                ),
+            ));
+
+        StateVectors {
+            position: Self::generate_starting_position(rng),
+            velocity: VectorValue::new(
+                starting_velocity,
+                Self::generate_starting_velocity_vector(
+                    rng,
+                ),
+            ),
+        }
+    }
+
+    /// Комета с заданными названием и начальным вектором состояния
+    pub fn with_state(
+        id: ObjectId,
+        name: Box<str>,
+        initial_state: StateVectors,
+        comet_possible_values: &CometPossibleValues,
+        image_index: usize,
+        trajectory_color: Color,
+        rng: &mut ChaCha12Rng,
+    ) -> Self {
+        // Перевод массы в физическую величину
+        let mass = Quantity::new(Kilograms::new(
+            Self::generate_mass(
+                comet_possible_values.masses,
+                rng,
+            ),
+        ));
+
+        // Перевод радиуса в физическую величину
+        let radius = Quantity::new(Kilometers::new(
+            Self::generate_radius(
+                comet_possible_values.radii,
+                rng,
+            ),
+        ));
+
+        Self::with_parameters(
+            id,
+            name,
+            CometParameters { initial_state, mass, radius },
+            comet_possible_values,
+            image_index,
+            trajectory_color,
+        )
+    }
+
+    /// Комета с заданными названием, начальным вектором состояния,
+    /// массой и радиусом
+    pub fn with_parameters(
+        id: ObjectId,
+        name: Box<str>,
+        parameters: CometParameters,
+        comet_possible_values: &CometPossibleValues,
+        image_index: usize,
+        trajectory_color: Color,
+    ) -> Self {
+        let CometParameters { initial_state, mass, radius } =
+            parameters;
+
+        // Создание движения объекта
+        let movement = ObjectMovement::new(
+            initial_state,
+            trajectory_color,
+        );
+
+        // Получение картинки из имеющихся
+        let image_path = comet_possible_values
+            .get_image_by_index(image_index)
+            .into();
+
+        Self {
+            id,
+            name,
+            movement,
+            mass,
+            radius,
+            image_path,
+            is_test_particle: false,
+        }
+    }
+
+    /// Название кометы по её номеру
+    #[inline(always)]
+    pub fn numbered_name(comet_number: u32) -> Box<str> {
+        format!("Comet {comet_number}").into_boxed_str()
+    }
+
+    /// Восстановление кометы из снимка
+    pub fn from_snapshot(snapshot: BodySnapshot) -> Self {
+        let BodySnapshot {
+            id,
+            name,
+            mass,
+            radius,
+            image_path,
+            movement,
+        } = snapshot;
+
+        Self {
+            id,
+            name,
+            movement: ObjectMovement::from_snapshot(
+                movement,
+            ),
+            mass: Quantity::new(Kilograms::new(mass)),
+            radius: Quantity::new(Kilometers::new(radius)),
+            image_path,
+            is_test_particle: false,
+        }
+    }
+
+    /// Генерация начального положения
+    fn generate_starting_position(
+        rng: &mut ChaCha12Rng,
+    ) -> Point<Quantity<Kilometers>> {
+        Point {
+            x: Quantity::new(Kilometers::new(
+                rng.random_range(-1e8..=1e8),
+            )),
+            y: Quantity::new(Kilometers::new(
+                rng.random_range(-1e8..=1e8),
+            )),
+        }
+    }
+
+    /// Генерация начальной скорости
+    fn generate_starting_velocity(
+        possible_velocities: (f64, f64),
+        rng: &mut ChaCha12Rng,
+    ) -> f64 {
+        rng.random_range(
+            possible_velocities.0..=possible_velocities.1,
+        )
+    }
+
+    /// Генерация единичного вектора начальной скорости
+    fn generate_starting_velocity_vector(
+        rng: &mut ChaCha12Rng,
+    ) -> Vector<f64> {
+        let velocity_x = rng.random_range(-1.0_f64..=1.);
+        let velocity_y = rng.random_range(-1.0_f64..=1.);
+        let velocity_vector_length = (velocity_x
+            * velocity_x
+            + velocity_y * velocity_y)
+            .sqrt();
+
+        Vector::new(
+            velocity_x / velocity_vector_length,
+            velocity_y / velocity_vector_length,
+        )
+    }
+
+    /// Генерация массы
+    fn generate_mass(
+        possible_masses: (f64, f64),
+        rng: &mut ChaCha12Rng,
+    ) -> f64 {
+        rng.random_range(
+            possible_masses.0..=possible_masses.1,
+        )
+    }
+
+    /// Генерация радиуса
+    fn generate_radius(
+        possible_radii: (f64, f64),
+        rng: &mut ChaCha12Rng,
+    ) -> f64 {
+        rng.random_range(
+            possible_radii.0..=possible_radii.1,
+        )
+    }
+}
+
+impl Comet {
+    #[inline(always)]
+    pub fn set_test_particle(
+        &mut self,
+        is_test_particle: bool,
+    ) {
+        self.is_test_particle = is_test_particle;
+    }
+}
+
+impl Object for Comet {
+    #[inline(always)]
+    fn id(&self) -> ObjectId {
+        self.id
+    }
+
+    #[inline(always)]
+    fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    #[inline(always)]
+    fn mass(&self) -> Quantity<Kilograms> {
+        self.mass
+    }
+
+    #[inline(always)]
+    fn radius(&self) -> Quantity<Kilometers> {
+        self.radius
+    }
+
+    #[inline(always)]
+    fn position(&self) -> Point<Quantity<Kilometers>> {
+        self.movement.position()
+    }
+
+    #[inline(always)]
+    fn image_path(&self) -> &str {
+        &self.image_path
+    }
+
+    #[inline(always)]
+    fn is_test_particle(&self) -> bool {
+        self.is_test_particle
+    }
+}
+
+impl ObjectMotion for Comet {
+    #[inline(always)]
+    fn movement(&self) -> &ObjectMovement {
+        &self.movement
+    }
+
+    #[inline(always)]
+    fn movement_mut(&mut self) -> &mut ObjectMovement {
+        &mut self.movement
+    }
+}
+
+/// Заданные пользователем параметры кометы
+pub struct CometParameters {
+    /// Начальный вектор состояния
+    pub initial_state: StateVectors,
+    /// Масса
+    pub mass: Quantity<Kilograms>,
+    /// Радиус
+    pub radius: Quantity<Kilometers>,
+}
+
+/// Заданные пользователем параметры кометы на орбите с перигелием
+pub struct CometOrbitParameters {
+    /// Прохождение перигелия, наклонение орбиты
+    /// задаёт только направление обращения
+    pub passage: PerihelionPassage,
+    /// Масса
+    pub mass: Quantity<Kilograms>,
+    /// Радиус
+    pub radius: Quantity<Kilometers>,
+}
+
+/// Возможные значения кометы
+#[derive(Clone, Getset)]
+pub struct CometPossibleValues {
+    /// Скорости (минимум, максимум)
+    #[getset(get_copy, vis = "pub")]
+    velocities: (f64, f64),
+    /// Массы (минимум, максимум)
+    #[getset(get_copy, vis = "pub")]
+    masses: (f64, f64),
+    /// Радиусы (минимум, максимум)
+    #[getset(get_copy, vis = "pub")]
+    radii: (f64, f64),
+    /// Пути к изображениям
+    images: Vec<Box<str>>,
+    /// Профили появления
+    spawn_profiles: Vec<SpawnProfile>,
+}
+
+impl CometPossibleValues {
+    /// Получения изображения по индексу
+    #[inline(always)]
+    pub fn get_image_by_index(&self, index: usize) -> &str {
+        &self.images[index]
+    }
+}
+
+impl CometPossibleValues {
+    #[inline(always)]
+    pub fn images(&self) -> &[Box<str>] {
+        self.images.as_slice()
+    }
+
+    #[inline(always)]
+    pub fn spawn_profiles(&self) -> &[SpawnProfile] {
+        self.spawn_profiles.as_slice()
+    }
+}
+
+impl CometPossibleValues {
+    pub fn new(
+        data: CometData,
+        path_to_images: &str,
+    ) -> Result<Self, SpawnProfileError> {
+        let CometData {
+            possible_velocities: velocities,
+            possible_masses: masses,
+            possible_radii: radii,
+            images_filenames,
+            spawn_profiles,
+        } = data;
+
+        // Пути к картинкам комет
+        let images = images_filenames
+            .into_iter()
+            .map(|image_filename| {
+                format!("{path_to_images}/{image_filename}")
+                    .into_boxed_str()
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Self {
+            velocities: (velocities[0], velocities[1]),
+            masses: (masses[0], masses[1]),
+            radii: (radii[0], radii[1]),
+            images,
+            spawn_profiles: spawn_profiles
+                .into_iter()
+                .map(SpawnProfile::new)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
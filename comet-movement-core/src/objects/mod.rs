@@ -0,0 +1,5 @@
+pub mod comet;
+pub mod planet;
+pub mod satellite;
+pub mod spawn_profile;
+pub mod sun;
@@ -1,24 +1,24 @@
 use std::{cell::RefCell, rc::Rc};
 
-use iced::{Color, Point, widget::image};
-
 use crate::{
     objects::satellite::Satellite,
     util::{
-        data::solar_system_data::ObjectConsts,
+        color::Color,
+        data::{
+            snapshot::{BodySnapshot, PlanetSnapshot},
+            solar_system_data::ObjectConsts,
+        },
+        geometry::point::Point,
         objects::{
             Object, ObjectMotion,
-            consts::SolarSystemObjectConsts,
+            consts::SolarSystemObjectConsts, id::ObjectId,
             movement::ObjectMovement,
         },
         physics::{
-            formulas::orbital_velocity,
+            orbit::StateVectors,
             quantities::{
                 Quantity,
-                quantity_units::{
-                    Kilograms, Kilometers,
-                    KilometersPerSecond,
-                },
+                quantity_units::{Kilograms, Kilometers},
             },
         },
     },
@@ -26,24 +26,28 @@ use crate::{
 
 /// Планета
 pub struct Planet {
+    /// Идентификатор
+    id: ObjectId,
     /// Название
     name: Box<str>,
     /// Константы
     consts: SolarSystemObjectConsts,
     /// Движение
     movement: ObjectMovement,
-    /// Изображение
-    image: image::Handle,
+    /// Начальный вектор состояния
+    initial_state: StateVectors,
+    /// Путь к изображению
+    image_path: Box<str>,
     /// Спутники
     satellites: Box<[Rc<RefCell<Satellite>>]>,
 }
 
 impl Planet {
     pub fn new(
+        id: ObjectId,
         planet_name: Box<str>,
-        initial_position: f32,
         planet_consts: ObjectConsts,
-        velocity: Quantity<KilometersPerSecond>,
+        initial_state: StateVectors,
         trajectory_color: Color,
         path_to_image: Box<str>,
         satellites: Vec<Satellite>,
@@ -51,14 +55,12 @@ impl Planet {
         // Запись констант
         let consts = SolarSystemObjectConsts::new(
             planet_consts.mass,
-            planet_consts.orbit,
             planet_consts.radius,
         );
 
         // Создание движения планеты
-        let movement = ObjectMovement::new_solar_system_object_movement(
-            velocity,
-            initial_position,
+        let movement = ObjectMovement::new(
+            initial_state.clone(),
             trajectory_color,
         );
 
@@ -71,46 +73,70 @@ impl Planet {
             .collect();
 
         Self {
+            id,
             name: planet_name,
             consts,
             movement,
-            image: image::Handle::from_path(
-                path_to_image.as_ref(),
-            ),
+            initial_state,
+            image_path: path_to_image,
             satellites,
         }
     }
-
-    /// Расчёт начальной позиции
-    pub fn initial_position(
-        sun_radius: f32,
-        planet_orbit: f32,
-        planet_radius: f32,
-    ) -> f32 {
-        sun_radius + planet_orbit + planet_radius
-    }
 }
 
 impl Planet {
-    #[inline(always)]
-    pub fn initial_orbit(&self) -> f32 {
-        self.consts.initial_orbit().value()
-    }
-
     #[inline(always)]
     pub fn satellites(&self) -> &[Rc<RefCell<Satellite>>] {
         self.satellites.as_ref()
     }
+}
 
-    #[inline(always)]
-    pub fn satellites_mut(
-        &mut self,
-    ) -> &mut [Rc<RefCell<Satellite>>] {
-        self.satellites.as_mut()
+impl Planet {
+    /// Планета со спутниками из снимка,
+    /// снимок становится начальным состоянием
+    pub fn from_snapshot(snapshot: PlanetSnapshot) -> Self {
+        let PlanetSnapshot {
+            body:
+                BodySnapshot {
+                    id,
+                    name,
+                    mass,
+                    radius,
+                    image_path,
+                    movement,
+                },
+            satellites,
+        } = snapshot;
+
+        Self {
+            id,
+            name,
+            consts: SolarSystemObjectConsts::new(
+                mass, radius,
+            ),
+            initial_state: movement.state(),
+            movement: ObjectMovement::from_snapshot(
+                movement,
+            ),
+            image_path,
+            satellites: satellites
+                .into_iter()
+                .map(|satellite| {
+                    Rc::new(RefCell::new(
+                        Satellite::from_snapshot(satellite),
+                    ))
+                })
+                .collect(),
+        }
     }
 }
 
 impl Object for Planet {
+    #[inline(always)]
+    fn id(&self) -> ObjectId {
+        self.id
+    }
+
     #[inline(always)]
     fn name(&self) -> &str {
         self.name.as_ref()
@@ -132,8 +158,8 @@ impl Object for Planet {
     }
 
     #[inline(always)]
-    fn image(&self) -> &image::Handle {
-        &self.image
+    fn image_path(&self) -> &str {
+        &self.image_path
     }
 }
 
@@ -150,22 +176,21 @@ impl ObjectMotion for Planet {
 }
 
 impl Planet {
-    // При перезагрузке симуляции
-    pub fn reload(
-        &mut self,
-        sun_mass: Quantity<Kilograms>,
-    ) {
-        let velocity = orbital_velocity(
-            sun_mass,
-            Quantity::new(Kilometers::new(
-                self.consts.initial_orbit().value(),
-            )),
+    /// Восстановление массы, радиуса и движения из снимка
+    pub fn restore(&mut self, snapshot: BodySnapshot) {
+        self.consts = SolarSystemObjectConsts::new(
+            snapshot.mass,
+            snapshot.radius,
         );
+        self.movement = ObjectMovement::from_snapshot(
+            snapshot.movement,
+        );
+    }
 
-        // Обновление движения
-        self.movement = ObjectMovement::new_solar_system_object_movement(
-            velocity,
-            self.consts.initial_orbit().value(),
+    // При перезагрузке симуляции
+    pub fn reload(&mut self) {
+        self.movement = ObjectMovement::new(
+            self.initial_state.clone(),
             self.movement.trajectory_color(),
         );
     }
@@ -0,0 +1,169 @@
+use crate::util::{
+    color::Color,
+    data::{
+        snapshot::BodySnapshot,
+        solar_system_data::SatelliteData,
+    },
+    geometry::point::Point,
+    objects::{
+        Object, ObjectMotion,
+        consts::SolarSystemObjectConsts, id::ObjectId,
+        movement::ObjectMovement,
+    },
+    physics::{
+        orbit::StateVectors,
+        quantities::{
+            Quantity,
+            quantity_units::{Kilograms, Kilometers},
+        },
+    },
+};
+
+/// Спутник
+pub struct Satellite {
+    /// Идентификатор
+    id: ObjectId,
+    /// Название
+    name: Box<str>,
+    /// Константы
+    consts: SolarSystemObjectConsts,
+    /// Движение
+    movement: ObjectMovement,
+    /// Начальный вектор состояния
+    initial_state: StateVectors,
+    /// Путь к изображению
+    image_path: Box<str>,
+}
+
+impl Satellite {
+    pub fn new(
+        id: ObjectId,
+        satellite_data: SatelliteData,
+        initial_state: StateVectors,
+        trajectory_color: Color,
+        path_to_images: &str,
+    ) -> Self {
+        let SatelliteData {
+            name,
+            consts: satellite_consts,
+            image_filename,
+            ..
+        } = satellite_data;
+
+        // Запись констант
+        let consts = SolarSystemObjectConsts::new(
+            satellite_consts.mass,
+            satellite_consts.radius,
+        );
+
+        // Создание движения спутника
+        let movement = ObjectMovement::new(
+            initial_state.clone(),
+            trajectory_color,
+        );
+
+        Self {
+            id,
+            name,
+            consts,
+            movement,
+            initial_state,
+            image_path: format!(
+                "{path_to_images}/{image_filename}"
+            )
+            .into_boxed_str(),
+        }
+    }
+}
+
+impl Satellite {
+    /// Спутник из снимка, снимок становится начальным состоянием
+    pub fn from_snapshot(snapshot: BodySnapshot) -> Self {
+        let BodySnapshot {
+            id,
+            name,
+            mass,
+            radius,
+            image_path,
+            movement,
+        } = snapshot;
+
+        Self {
+            id,
+            name,
+            consts: SolarSystemObjectConsts::new(
+                mass, radius,
+            ),
+            initial_state: movement.state(),
+            movement: ObjectMovement::from_snapshot(
+                movement,
+            ),
+            image_path,
+        }
+    }
+}
+
+impl Object for Satellite {
+    #[inline(always)]
+    fn id(&self) -> ObjectId {
+        self.id
+    }
+
+    #[inline(always)]
+    fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    #[inline(always)]
+    fn mass(&self) -> Quantity<Kilograms> {
+        self.consts.mass()
+    }
+
+    #[inline(always)]
+    fn radius(&self) -> Quantity<Kilometers> {
+        self.consts.radius()
+    }
+
+    #[inline(always)]
+    fn position(&self) -> Point<Quantity<Kilometers>> {
+        self.movement.position()
+    }
+
+    #[inline(always)]
+    fn image_path(&self) -> &str {
+        &self.image_path
+    }
+}
+
+impl ObjectMotion for Satellite {
+    #[inline(always)]
+    fn movement(&self) -> &ObjectMovement {
+        &self.movement
+    }
+
+    #[inline(always)]
+    fn movement_mut(&mut self) -> &mut ObjectMovement {
+        &mut self.movement
+    }
+}
+
+impl Satellite {
+    /// Восстановление массы, радиуса и движения из снимка
+    pub fn restore(&mut self, snapshot: BodySnapshot) {
+        self.consts = SolarSystemObjectConsts::new(
+            snapshot.mass,
+            snapshot.radius,
+        );
+        self.movement = ObjectMovement::from_snapshot(
+            snapshot.movement,
+        );
+    }
+
+    // При перезагрузке симуляции
+    pub fn reload(&mut self) {
+        self.movement = ObjectMovement::new(
+            self.initial_state.clone(),
+            self.movement.trajectory_color(),
+        );
+    }
+}
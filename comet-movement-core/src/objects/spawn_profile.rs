@@ -0,0 +1,191 @@
+use std::fmt::{Display, Formatter};
+
+use gset::Getset;
+use rand::{RngExt, rngs::ChaCha12Rng};
+
+use crate::util::{
+    data::solar_system_data::SpawnProfileData,
+    physics::{
+        orbit::{PerihelionOrbit, PerihelionPassage},
+        quantities::{
+            Quantity,
+            quantity_units::{Kilometers, Seconds},
+        },
+    },
+};
+
+/// Астрономическая единица в км
+const ASTRONOMICAL_UNIT: f64 = 149_597_870.7;
+/// Секунд в сутках
+const SECONDS_PER_DAY: f64 = 86_400.;
+
+/// Профиль появления комет: семейство орбит,
+/// из диапазонов элементов которых выбирается новая комета
+#[derive(Clone, Getset)]
+pub struct SpawnProfile {
+    /// Название
+    #[getset(get, vis = "pub")]
+    name: Box<str>,
+    /// Расстояние в перигелии (в км)
+    perihelion_distance: (f64, f64),
+    /// Эксцентриситет
+    eccentricity: (f64, f64),
+    /// Аргумент перигелия (в градусах)
+    argument_of_perihelion: (f64, f64),
+    /// Долгота восходящего узла (в градусах)
+    longitude_of_ascending_node: (f64, f64),
+    /// Наклонение (в градусах)
+    inclination: (f64, f64),
+    /// Время до прохождения перигелия (в секундах)
+    time_to_perihelion: (f64, f64),
+}
+
+impl SpawnProfile {
+    /// Профиль из данных с проверкой диапазонов
+    pub fn new(
+        data: SpawnProfileData,
+    ) -> Result<Self, SpawnProfileError> {
+        let SpawnProfileData {
+            name,
+            perihelion_distance,
+            eccentricity,
+            argument_of_perihelion,
+            longitude_of_ascending_node,
+            inclination,
+            time_to_perihelion,
+        } = data;
+
+        // Диапазон должен быть упорядочен и лежать в допустимых границах
+        let range = |field: &'static str,
+                     [minimum, maximum]: [f64; 2],
+                     (lower, upper): (f64, f64),
+                     factor: f64| {
+            if minimum.is_finite()
+                && maximum.is_finite()
+                && lower <= minimum
+                && minimum <= maximum
+                && maximum <= upper
+            {
+                Ok((minimum * factor, maximum * factor))
+            } else {
+                Err(SpawnProfileError::InvalidRange {
+                    profile: name.clone(),
+                    field,
+                    range: [minimum, maximum],
+                })
+            }
+        };
+
+        let any = (f64::NEG_INFINITY, f64::INFINITY);
+
+        Ok(Self {
+            perihelion_distance: range(
+                "perihelion distance",
+                perihelion_distance,
+                (f64::MIN_POSITIVE, f64::INFINITY),
+                ASTRONOMICAL_UNIT,
+            )?,
+            eccentricity: range(
+                "eccentricity",
+                eccentricity,
+                (0., f64::INFINITY),
+                1.,
+            )?,
+            argument_of_perihelion: range(
+                "argument of perihelion",
+                argument_of_perihelion,
+                any,
+                1.,
+            )?,
+            longitude_of_ascending_node: range(
+                "longitude of ascending node",
+                longitude_of_ascending_node,
+                any,
+                1.,
+            )?,
+            inclination: range(
+                "inclination",
+                inclination,
+                (0., 180.),
+                1.,
+            )?,
+            time_to_perihelion: range(
+                "time to perihelion",
+                time_to_perihelion,
+                any,
+                SECONDS_PER_DAY,
+            )?,
+            name,
+        })
+    }
+}
+
+impl SpawnProfile {
+    /// Случайное прохождение перигелия на орбите профиля
+    pub fn generate_passage(
+        &self,
+        rng: &mut ChaCha12Rng,
+    ) -> PerihelionPassage {
+        let mut generate =
+            |(minimum, maximum): (f64, f64)| {
+                rng.random_range(minimum..=maximum)
+            };
+
+        let orbit = PerihelionOrbit::new(
+            Quantity::new(Kilometers::new(generate(
+                self.perihelion_distance,
+            ))),
+            generate(self.eccentricity),
+            generate(self.argument_of_perihelion),
+            generate(self.longitude_of_ascending_node),
+            // Направления орбит распределены изотропно:
+            // равномерно выбирается косинус наклонения,
+            // от знака которого зависит направление обращения
+            generate((
+                self.inclination.1.to_radians().cos(),
+                self.inclination.0.to_radians().cos(),
+            ))
+            .clamp(-1., 1.)
+            .acos()
+            .to_degrees(),
+        );
+
+        PerihelionPassage {
+            orbit,
+            time_to_perihelion: Quantity::new(
+                Seconds::new(generate(
+                    self.time_to_perihelion,
+                )),
+            ),
+        }
+    }
+}
+
+/// Ошибка в данных профиля появления комет
+#[derive(Debug)]
+pub enum SpawnProfileError {
+    /// Диапазон не упорядочен или выходит за допустимые границы
+    InvalidRange {
+        profile: Box<str>,
+        field: &'static str,
+        range: [f64; 2],
+    },
+}
+
+impl Display for SpawnProfileError {
+    fn fmt(
+        &self,
+        fmt: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Self::InvalidRange {
+                profile,
+                field,
+                range: [minimum, maximum],
+            } => write!(
+                fmt,
+                "profile \"{profile}\": invalid {field} range [{minimum}, {maximum}]"
+            ),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::util::{
+    color::Color,
+    data::{
+        snapshot::BodySnapshot, solar_system_data::SunData,
+    },
+    geometry::point::Point,
+    objects::{
+        Object, ObjectMotion,
+        consts::SolarSystemObjectConsts, id::ObjectId,
+        movement::ObjectMovement,
+    },
+    physics::{
+        orbit::StateVectors,
+        quantities::{
+            Quantity,
+            quantity_units::{Kilograms, Kilometers},
+        },
+    },
+};
+
+pub struct Sun {
+    /// Идентификатор
+    id: ObjectId,
+    consts: SolarSystemObjectConsts,
+    /// Движение
+    movement: ObjectMovement,
+    /// Путь к изображению
+    image_path: Box<str>,
+}
+
+impl Sun {
+    pub fn new(
+        id: ObjectId,
+        data: SunData,
+        path_to_images: &str,
+        trajectory_color: Color,
+    ) -> Self {
+        let SunData { consts, image_filename } = data;
+
+        Self {
+            id,
+            consts: SolarSystemObjectConsts::new(
+                consts.mass,
+                consts.radius,
+            ),
+            movement: Self::initial_movement(
+                trajectory_color,
+            ),
+            image_path: format!(
+                "{path_to_images}/{image_filename}"
+            )
+            .into_boxed_str(),
+        }
+    }
+
+    /// Солнце из снимка
+    pub fn from_snapshot(snapshot: BodySnapshot) -> Self {
+        let BodySnapshot {
+            id,
+            mass,
+            radius,
+            image_path,
+            movement,
+            ..
+        } = snapshot;
+
+        Self {
+            id,
+            consts: SolarSystemObjectConsts::new(
+                mass, radius,
+            ),
+            movement: ObjectMovement::from_snapshot(
+                movement,
+            ),
+            image_path,
+        }
+    }
+
+    /// Начальное движение: покой в начале координат
+    fn initial_movement(
+        trajectory_color: Color,
+    ) -> ObjectMovement {
+        ObjectMovement::new(
+            StateVectors::at_rest(),
+            trajectory_color,
+        )
+    }
+}
+
+impl Object for Sun {
+    #[inline(always)]
+    fn id(&self) -> ObjectId {
+        self.id
+    }
+
+    #[inline(always)]
+    fn name(&self) -> &str {
+        "Sun"
+    }
+
+    #[inline(always)]
+    fn mass(&self) -> Quantity<Kilograms> {
+        self.consts.mass()
+    }
+
+    #[inline(always)]
+    fn radius(&self) -> Quantity<Kilometers> {
+        self.consts.radius()
+    }
+
+    #[inline(always)]
+    fn position(&self) -> Point<Quantity<Kilometers>> {
+        self.movement.position()
+    }
+
+    #[inline(always)]
+    fn image_path(&self) -> &str {
+        &self.image_path
+    }
+}
+
+impl ObjectMotion for Sun {
+    #[inline(always)]
+    fn movement(&self) -> &ObjectMovement {
+        &self.movement
+    }
+
+    #[inline(always)]
+    fn movement_mut(&mut self) -> &mut ObjectMovement {
+        &mut self.movement
+    }
+}
+
+impl Sun {
+    /// Восстановление массы, радиуса и движения из снимка
+    pub fn restore(&mut self, snapshot: BodySnapshot) {
+        self.consts = SolarSystemObjectConsts::new(
+            snapshot.mass,
+            snapshot.radius,
+        );
+        self.movement = ObjectMovement::from_snapshot(
+            snapshot.movement,
+        );
+    }
+
+    // При перезагрузке симуляции
+    pub fn reload(&mut self) {
+        self.movement = Self::initial_movement(
+            self.movement.trajectory_color(),
+        );
+    }
+}
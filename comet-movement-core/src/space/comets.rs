@@ -0,0 +1,488 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use gset::Getset;
+use rand::{prelude::IteratorRandom, rngs::ChaCha12Rng};
+use tap::Tap;
+
+use crate::{
+    objects::comet::{
+        Comet, CometParameters, CometPossibleValues,
+    },
+    util::{
+        color::Color,
+        data::{
+            snapshot::{
+                BodySnapshot, CometsSnapshot, SnapshotError,
+            },
+            update::{CometUpdate, CometsUpdate},
+        },
+        objects::{
+            MovingObject, Object, ObjectMotion,
+            id::{ObjectId, ObjectIdGenerator},
+        },
+        physics::orbit::StateVectors,
+    },
+};
+
+/// Состояние комет
+#[derive(Getset)]
+pub struct CometsState {
+    /// Возможные значения
+    #[getset(get, vis = "pub")]
+    possible_values: CometPossibleValues,
+    /// Кометы
+    comets: Vec<Rc<RefCell<Comet>>>,
+    /// Цвета траекторий всех объектов, новые кометы их не повторяют
+    trajectory_colors: HashSet<Color>,
+    /// Новый номер кометы
+    #[getset(get_copy, vis = "pub")]
+    new_comet_number: u32,
+    /// Максимальное количество комет, без него кометы не ограничены
+    maximum_number_of_comets: Option<u32>,
+    /// Являются ли кометы пробными частицами
+    are_test_particles: bool,
+}
+
+impl CometsState {
+    #[inline(always)]
+    pub fn new(
+        possible_values: CometPossibleValues,
+        trajectory_colors: HashSet<Color>,
+        maximum_number_of_comets: Option<u32>,
+        are_test_particles: bool,
+    ) -> Self {
+        Self {
+            possible_values,
+            comets: Vec::new(),
+            trajectory_colors,
+            new_comet_number: 1,
+            maximum_number_of_comets,
+            are_test_particles,
+        }
+    }
+}
+
+impl CometsState {
+    /// Подсчёт комет
+    #[inline(always)]
+    pub fn count(&self) -> usize {
+        self.comets.len()
+    }
+
+    /// Достигнуто ли максимальное количество комет
+    #[inline(always)]
+    pub fn is_full(&self) -> bool {
+        self.maximum_number_of_comets.is_some_and(
+            |maximum| self.comets.len() >= maximum as usize,
+        )
+    }
+
+    #[inline(always)]
+    pub fn maximum_number_of_comets(&self) -> Option<u32> {
+        self.maximum_number_of_comets
+    }
+}
+
+impl CometsState {
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[Rc<RefCell<Comet>>] {
+        self.comets.as_slice()
+    }
+}
+
+impl CometsState {
+    /// Добавление новой кометы с заданным начальным вектором состояния
+    pub fn add_new_comet_with_state(
+        &mut self,
+        object_ids: &mut ObjectIdGenerator,
+        initial_state: StateVectors,
+        rng: &mut ChaCha12Rng,
+    ) -> Rc<RefCell<Comet>> {
+        let comet_number = self.new_comet_number;
+
+        let comet = self.push_comet(
+            rng,
+            |possible_values,
+             image_index,
+             trajectory_color,
+             rng| {
+                Comet::with_state(
+                    object_ids.generate(),
+                    Comet::numbered_name(comet_number),
+                    initial_state,
+                    possible_values,
+                    image_index,
+                    trajectory_color,
+                    rng,
+                )
+            },
+        );
+
+        self.new_comet_number += 1;
+
+        comet
+    }
+
+    /// Добавление кометы с заданными параметрами под новым номером
+    pub fn add_comet_with_parameters(
+        &mut self,
+        object_ids: &mut ObjectIdGenerator,
+        parameters: CometParameters,
+        rng: &mut ChaCha12Rng,
+    ) -> Rc<RefCell<Comet>> {
+        let comet_number = self.new_comet_number;
+
+        let comet = self.push_comet(
+            rng,
+            |possible_values,
+             image_index,
+             trajectory_color,
+             _| {
+                Comet::with_parameters(
+                    object_ids.generate(),
+                    Comet::numbered_name(comet_number),
+                    parameters,
+                    possible_values,
+                    image_index,
+                    trajectory_color,
+                )
+            },
+        );
+
+        self.new_comet_number += 1;
+
+        comet
+    }
+
+    /// Добавление кометы с заданными названием и начальным вектором состояния,
+    /// номер новой кометы при этом не расходуется
+    pub fn add_comet_with_state(
+        &mut self,
+        object_ids: &mut ObjectIdGenerator,
+        name: Box<str>,
+        initial_state: StateVectors,
+        rng: &mut ChaCha12Rng,
+    ) -> Rc<RefCell<Comet>> {
+        self.push_comet(
+            rng,
+            |possible_values,
+             image_index,
+             trajectory_color,
+             rng| {
+                Comet::with_state(
+                    object_ids.generate(),
+                    name,
+                    initial_state,
+                    possible_values,
+                    image_index,
+                    trajectory_color,
+                    rng,
+                )
+            },
+        )
+    }
+
+    /// Создание кометы с наименее занятым изображением и новым цветом
+    fn push_comet(
+        &mut self,
+        rng: &mut ChaCha12Rng,
+        create_comet: impl FnOnce(
+            &CometPossibleValues,
+            usize,
+            Color,
+            &mut ChaCha12Rng,
+        ) -> Comet,
+    ) -> Rc<RefCell<Comet>> {
+        let trajectory_color = Color::generate_unique(
+            &mut self.trajectory_colors,
+            rng,
+        );
+
+        let image_index = self.free_comet_image_index(rng);
+
+        let comet = Rc::new(RefCell::new(
+            create_comet(
+                &self.possible_values,
+                image_index,
+                trajectory_color,
+                rng,
+            )
+            .tap_mut(|comet| {
+                comet.set_test_particle(
+                    self.are_test_particles,
+                )
+            }),
+        ));
+
+        self.comets.push(comet.clone());
+
+        comet
+    }
+
+    /// Поиск индекса изображения, которое меньше всего занято кометами
+    fn free_comet_image_index(
+        &self,
+        rng: &mut ChaCha12Rng,
+    ) -> usize {
+        let images = self.possible_values.images();
+
+        // Количество комет с каждым изображением
+        let mut images_usage = vec![0_usize; images.len()];
+        self.comets.iter().for_each(|comet| {
+            if let Some(index) =
+                images.iter().position(|image| {
+                    **image == *comet.borrow().image_path()
+                })
+            {
+                images_usage[index] += 1;
+            }
+        });
+
+        let least_usage = images_usage
+            .iter()
+            .copied()
+            .min()
+            .expect("Comet images must not be empty");
+
+        // Выборка случайного индекса наименее занятого изображения
+        images_usage
+            .iter()
+            .enumerate()
+            .filter(|(_, usage)| **usage == least_usage)
+            .map(|(index, _)| index)
+            .choose(rng)
+            .expect("Least used image must exist")
+    }
+}
+
+impl CometsState {
+    /// Удаление кометы, возвращает, была ли она удалена
+    pub fn delete_comet(&mut self, id: ObjectId) -> bool {
+        // Если комета с таким идентификатором существует
+        let Some(index) = self
+            .comets
+            .iter()
+            .position(|comet| comet.borrow().id() == id)
+        else {
+            return false;
+        };
+
+        let comet = self.comets.swap_remove(index);
+
+        // Цвет кометы освобождается
+        self.trajectory_colors
+            .remove(&comet.borrow().trajectory_color());
+
+        true
+    }
+
+    /// Удаление комет, не удовлетворяющих условию,
+    /// возвращает количество удалённых
+    pub fn retain(
+        &mut self,
+        mut is_kept: impl FnMut(&Comet) -> bool,
+    ) -> usize {
+        let count = self.comets.len();
+
+        self.comets.retain(|comet| {
+            let comet = comet.borrow();
+            let is_comet_kept = is_kept(&comet);
+
+            // Цвет удалённой кометы освобождается
+            if !is_comet_kept {
+                self.trajectory_colors
+                    .remove(&comet.trajectory_color());
+            }
+
+            is_comet_kept
+        });
+
+        count - self.comets.len()
+    }
+
+    /// Освобождение цветов всех комет
+    fn release_comets_colors(&mut self) {
+        self.comets.iter().for_each(|comet| {
+            self.trajectory_colors
+                .remove(&comet.borrow().trajectory_color());
+        });
+    }
+}
+
+impl CometsState {
+    /// Снимок состояния комет
+    pub fn snapshot(
+        &self,
+        with_trajectories: bool,
+    ) -> CometsSnapshot {
+        CometsSnapshot {
+            new_comet_number: self.new_comet_number,
+            are_test_particles: self.are_test_particles,
+            comets: self
+                .comets
+                .iter()
+                .map(|comet| {
+                    BodySnapshot::of(
+                        &*comet.borrow(),
+                        with_trajectories,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Проверка, что снимок комет можно восстановить
+    pub fn check_snapshot(
+        &self,
+        snapshot: &CometsSnapshot,
+    ) -> Result<(), SnapshotError> {
+        if let Some(maximum) = self.maximum_number_of_comets
+            && snapshot.comets.len() > maximum as usize
+        {
+            return Err(SnapshotError::TooManyComets {
+                count: snapshot.comets.len(),
+                maximum,
+            });
+        }
+
+        // Интерфейс загружает только известные изображения комет
+        let images = self.possible_values.images();
+        if let Some(comet) =
+            snapshot.comets.iter().find(|comet| {
+                !images.contains(&comet.image_path)
+            })
+        {
+            return Err(SnapshotError::UnknownCometImage {
+                name: comet.name.clone(),
+                image_path: comet.image_path.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Замена комет на кометы из снимка
+    pub fn restore(
+        &mut self,
+        snapshot: CometsSnapshot,
+    ) -> &[Rc<RefCell<Comet>>] {
+        let CometsSnapshot {
+            new_comet_number,
+            are_test_particles,
+            comets,
+        } = snapshot;
+
+        self.release_comets_colors();
+        self.are_test_particles = are_test_particles;
+
+        self.comets.clear();
+        self.comets.extend(comets.into_iter().map(
+            |comet| {
+                Rc::new(RefCell::new(
+                    Comet::from_snapshot(comet).tap_mut(
+                        |comet| {
+                            comet.set_test_particle(
+                                self.are_test_particles,
+                            )
+                        },
+                    ),
+                ))
+            },
+        ));
+
+        // Цвета, занятые восстановленными кометами
+        self.comets.iter().for_each(|comet| {
+            self.trajectory_colors
+                .insert(comet.borrow().trajectory_color());
+        });
+
+        self.new_comet_number = new_comet_number;
+
+        self.comets.as_slice()
+    }
+}
+
+impl CometsState {
+    /// Применение изменения к копии комет,
+    /// возвращаются новые кометы
+    pub fn apply_update(
+        &mut self,
+        update: CometsUpdate,
+    ) -> Vec<Rc<RefCell<Comet>>> {
+        let CometsUpdate { new_comet_number, comets } =
+            update;
+
+        let kept_ids = comets
+            .iter()
+            .filter_map(|comet| match comet {
+                CometUpdate::Existing { id, .. } => {
+                    Some(*id)
+                }
+                CometUpdate::New(_) => None,
+            })
+            .collect::<HashSet<_>>();
+
+        let mut kept_comets = HashMap::new();
+        self.comets.drain(..).for_each(|comet| {
+            let id = comet.borrow().id();
+
+            if kept_ids.contains(&id) {
+                kept_comets.insert(id, comet);
+            } else {
+                // Цвет удалённой кометы освобождается
+                self.trajectory_colors.remove(
+                    &comet.borrow().trajectory_color(),
+                );
+            }
+        });
+
+        let mut new_comets = Vec::new();
+
+        self.comets = comets
+            .into_iter()
+            .map(|comet| match comet {
+                CometUpdate::Existing { id, movement } => {
+                    let comet = kept_comets
+                        .remove(&id)
+                        .expect("Updated comet must exist");
+                    comet
+                        .borrow_mut()
+                        .movement_mut()
+                        .apply_update(movement);
+                    comet
+                }
+                CometUpdate::New(snapshot) => {
+                    let comet = Rc::new(RefCell::new(
+                        Comet::from_snapshot(snapshot)
+                            .tap_mut(|comet| {
+                                comet.set_test_particle(
+                                    self.are_test_particles,
+                                )
+                            }),
+                    ));
+                    self.trajectory_colors.insert(
+                        comet.borrow().trajectory_color(),
+                    );
+                    new_comets.push(comet.clone());
+                    comet
+                }
+            })
+            .collect();
+
+        self.new_comet_number = new_comet_number;
+
+        new_comets
+    }
+}
+
+impl CometsState {
+    pub fn reload(&mut self) {
+        self.release_comets_colors();
+        self.new_comet_number = 1;
+        self.comets.clear();
+    }
+}
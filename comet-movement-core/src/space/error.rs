@@ -0,0 +1,91 @@
+use std::{
+    fmt::{Display, Formatter},
+    io,
+    path::PathBuf,
+};
+
+use crate::{
+    objects::spawn_profile::SpawnProfileError,
+    util::data::{
+        comet_elements::CometElementsError,
+        horizons_vectors::HorizonsError,
+    },
+};
+
+/// Ошибка загрузки данных при создании состояния космоса
+#[derive(Debug)]
+pub enum SpaceError {
+    /// Ошибка чтения файла с данными Солнечной системы
+    Io { path: PathBuf, error: io::Error },
+    /// Некорректные данные Солнечной системы
+    Data { path: PathBuf, error: serde_json::Error },
+    /// Ошибка импорта таблиц векторов JPL Horizons
+    Horizons(HorizonsError),
+    /// Ошибка в профилях появления комет
+    SpawnProfile(SpawnProfileError),
+    /// Ошибка импорта элементов орбит комет
+    CometElements(CometElementsError),
+    /// Импортируемых комет больше максимального количества
+    TooManyImportedComets { count: usize, maximum: u32 },
+}
+
+impl From<HorizonsError> for SpaceError {
+    fn from(error: HorizonsError) -> Self {
+        Self::Horizons(error)
+    }
+}
+
+impl From<SpawnProfileError> for SpaceError {
+    fn from(error: SpawnProfileError) -> Self {
+        Self::SpawnProfile(error)
+    }
+}
+
+impl From<CometElementsError> for SpaceError {
+    fn from(error: CometElementsError) -> Self {
+        Self::CometElements(error)
+    }
+}
+
+impl Display for SpaceError {
+    fn fmt(
+        &self,
+        fmt: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(
+                fmt,
+                "can not read {}: {error}",
+                path.display()
+            ),
+            Self::Data { path, error } => write!(
+                fmt,
+                "can not read data from {}: {error}",
+                path.display()
+            ),
+            Self::Horizons(error) => write!(
+                fmt,
+                "can not import Horizons vectors: {error}"
+            ),
+            Self::SpawnProfile(error) => write!(
+                fmt,
+                "can not read spawn profiles: {error}"
+            ),
+            Self::CometElements(error) => {
+                write!(
+                    fmt,
+                    "can not import comets: {error}"
+                )
+            }
+            Self::TooManyImportedComets {
+                count,
+                maximum,
+            } => {
+                write!(
+                    fmt,
+                    "can not import {count} comets, maximum number of comets is {maximum}"
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,1355 @@
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    fs::File,
+    io::BufReader,
+    rc::{Rc, Weak},
+};
+
+use gset::Getset;
+use rand::rngs::ChaCha12Rng;
+use serde_json::from_reader;
+use tap::Tap;
+
+use crate::{
+    config::Config,
+    objects::{
+        comet::{
+            Comet, CometOrbitParameters, CometParameters,
+            CometPossibleValues,
+        },
+        planet::Planet,
+        satellite::Satellite,
+        sun::Sun,
+    },
+    space::{
+        comets::CometsState, error::SpaceError,
+        spawn::SpawnError,
+    },
+    util::{
+        color::Color,
+        data::{
+            comet_elements::CometElements,
+            horizons_vectors::HorizonsStates,
+            snapshot::{
+                BodySnapshot, PlanetSnapshot,
+                SnapshotError, SpaceSnapshot,
+            },
+            solar_system_data::{Data, PlanetData},
+            update::{
+                CometUpdate, CometsUpdate, PlanetUpdate,
+                PublishedPositions, SpaceUpdate,
+            },
+        },
+        geometry::{
+            circle::{
+                Circle, is_circles_have_common_points,
+            },
+            point::Point,
+            vector::Vector,
+        },
+        objects::{
+            MovingObject, Object, ObjectMotion,
+            id::{ObjectId, ObjectIdGenerator},
+            values::FormValues,
+        },
+        physics::{
+            integrators::{
+                Body, GravitationalSystem, Integrator,
+                step_control::StepControl,
+            },
+            orbit::{
+                OrbitalElements, PerihelionPassage,
+                StateVectors,
+            },
+            quantities::{
+                Quantity,
+                quantity_units::{
+                    Kilograms, Kilometers, Seconds,
+                },
+            },
+        },
+    },
+};
+
+pub mod comets;
+pub mod error;
+pub mod spawn;
+
+/// Секунд в сутках
+const SECONDS_PER_DAY: f64 = 86_400.;
+
+/// Состояние космических объектов
+#[derive(Getset)]
+pub struct SpaceState {
+    /// Планеты
+    planets: Vec<Rc<RefCell<Planet>>>,
+    /// Солнце
+    #[getset(get, vis = "pub")]
+    sun: Rc<RefCell<Sun>>,
+    /// Все объекты
+    all_objects: Vec<Weak<RefCell<dyn Object>>>,
+    /// Двигающиеся объекты
+    moving_objects: Vec<Weak<RefCell<dyn MovingObject>>>,
+    /// Состояние комет
+    comets: CometsState,
+    /// Названия и начальные векторы состояния импортированных комет
+    imported_comets: Vec<(Box<str>, StateVectors)>,
+    /// Генератор идентификаторов объектов
+    object_ids: ObjectIdGenerator,
+    /// Двигается ли Солнце (полная задача N тел в барицентрической системе)
+    #[getset(get_copy, vis = "pub")]
+    is_sun_moving: bool,
+    /// Записываются ли траектории (без окна они не нужны)
+    #[getset(get_copy, vis = "pub")]
+    is_recording_trajectories: bool,
+}
+
+impl SpaceState {
+    pub fn new(
+        config: &Config,
+        rng: &mut ChaCha12Rng,
+    ) -> Result<Self, SpaceError> {
+        let mut object_ids = ObjectIdGenerator::new();
+
+        let (
+            sun,
+            planets,
+            comet_possible_values,
+            trajectory_colors,
+        ) = Self::get_solar_system_data(
+            config,
+            &mut object_ids,
+            rng,
+        )?;
+
+        let imported_comets =
+            Self::get_imported_comets(config, sun.mass())?;
+
+        let sun = Rc::new(RefCell::new(sun));
+
+        let planets = planets
+            .into_iter()
+            .map(|planet| Rc::new(RefCell::new(planet)))
+            .collect::<Vec<_>>();
+
+        let all_objects =
+            Self::get_all_objects(&sun, planets.as_slice());
+
+        let moving_objects = Self::get_moving_objects(
+            &sun,
+            planets.as_slice(),
+            config.is_sun_moving(),
+        );
+
+        let mut space = Self {
+            planets,
+            sun,
+            all_objects,
+            moving_objects,
+            comets: CometsState::new(
+                comet_possible_values,
+                trajectory_colors,
+                config.maximum_number_of_comets(),
+                config.are_comets_test_particles(),
+            ),
+            imported_comets,
+            object_ids,
+            is_sun_moving: config.is_sun_moving(),
+            is_recording_trajectories: true,
+        };
+
+        space.add_imported_comets(rng);
+
+        if space.is_sun_moving {
+            space.move_to_barycentric_frame();
+        }
+
+        Ok(space)
+    }
+
+    /// Составление массива всех объектов
+    fn get_all_objects(
+        sun: &Rc<RefCell<Sun>>,
+        planets: &[Rc<RefCell<Planet>>],
+    ) -> Vec<Weak<RefCell<dyn Object>>> {
+        Vec::<Weak<RefCell<dyn Object>>>::new().tap_mut(
+            |all_objects| {
+                let sun: Rc<RefCell<dyn Object>> =
+                    sun.clone();
+                all_objects.push(Rc::downgrade(&sun));
+
+                planets.iter().for_each(|planet| {
+                    let planet_as_object: Rc<
+                        RefCell<dyn Object>,
+                    > = planet.clone();
+                    all_objects.push(Rc::downgrade(
+                        &planet_as_object,
+                    ));
+
+                    planet
+                        .borrow()
+                        .satellites()
+                        .iter()
+                        .for_each(|satellite| {
+                            let satellite: Rc<
+                                RefCell<dyn Object>,
+                            > = satellite.clone();
+                            all_objects.push(
+                                Rc::downgrade(&satellite),
+                            );
+                        });
+                });
+            },
+        )
+    }
+
+    /// Составление всех двигающихся объектов
+    fn get_moving_objects(
+        sun: &Rc<RefCell<Sun>>,
+        planets: &[Rc<RefCell<Planet>>],
+        is_sun_moving: bool,
+    ) -> Vec<Weak<RefCell<dyn MovingObject>>> {
+        Vec::<Weak<RefCell<dyn MovingObject>>>::new()
+            .tap_mut(|moving_objects| {
+                if is_sun_moving {
+                    let sun: Rc<RefCell<dyn MovingObject>> =
+                        sun.clone();
+                    moving_objects
+                        .push(Rc::downgrade(&sun));
+                }
+
+                planets.iter().for_each(|planet| {
+                    let planet_as_object: Rc<
+                        RefCell<dyn MovingObject>,
+                    > = planet.clone();
+                    moving_objects.push(Rc::downgrade(
+                        &planet_as_object,
+                    ));
+
+                    planet
+                        .borrow()
+                        .satellites()
+                        .iter()
+                        .for_each(|satellite| {
+                            let satellite: Rc<
+                                RefCell<dyn MovingObject>,
+                            > = satellite.clone();
+                            moving_objects.push(
+                                Rc::downgrade(&satellite),
+                            );
+                        });
+                });
+            })
+    }
+
+    /// Получение данных для объектов Солнечной системы
+    fn get_solar_system_data(
+        config: &Config,
+        object_ids: &mut ObjectIdGenerator,
+        rng: &mut ChaCha12Rng,
+    ) -> Result<
+        (
+            Sun,
+            Vec<Planet>,
+            CometPossibleValues,
+            HashSet<Color>,
+        ),
+        SpaceError,
+    > {
+        let path_to_images = config.path_to_assets();
+        let start_julian_date = config.start_julian_date();
+        let path = config.path_to_solar_system_values();
+
+        let file = File::open(path).map_err(|error| {
+            SpaceError::Io { path: path.into(), error }
+        })?;
+
+        let Data {
+            epoch,
+            sun: sun_data,
+            planets: planets_data,
+            comet: comet_data,
+        } = from_reader(BufReader::new(file)).map_err(
+            |error| SpaceError::Data {
+                path: path.into(),
+                error,
+            },
+        )?;
+
+        // Векторы состояния из таблиц JPL Horizons на начальную дату
+        let horizons_states = config
+            .path_to_horizons_vectors()
+            .map(|path| {
+                HorizonsStates::read_directory(
+                    path,
+                    start_julian_date,
+                )
+            })
+            .transpose()?;
+
+        let mut trajectory_colors = HashSet::new();
+        let mut generate_trajectory_color = || {
+            Color::generate_unique(
+                &mut trajectory_colors,
+                rng,
+            )
+        };
+
+        let sun = Sun::new(
+            object_ids.generate(),
+            sun_data,
+            path_to_images,
+            generate_trajectory_color(),
+        );
+
+        let planets = Self::get_planets_data(
+            planets_data,
+            &mut generate_trajectory_color,
+            sun.mass(),
+            Quantity::new(Seconds::new(
+                (start_julian_date - epoch)
+                    * SECONDS_PER_DAY,
+            )),
+            horizons_states.as_ref(),
+            path_to_images,
+            object_ids,
+        );
+
+        let comet_values = CometPossibleValues::new(
+            comet_data,
+            path_to_images,
+        )?;
+
+        // Цвета Солнца, планет и спутников не достаются кометам
+        Ok((sun, planets, comet_values, trajectory_colors))
+    }
+
+    /// Получение начальных векторов состояния выбранных комет
+    /// из файла элементов орбит Центра малых планет
+    fn get_imported_comets(
+        config: &Config,
+        sun_mass: Quantity<Kilograms>,
+    ) -> Result<Vec<(Box<str>, StateVectors)>, SpaceError>
+    {
+        let Some(path) = config.path_to_comet_elements()
+        else {
+            return Ok(Vec::new());
+        };
+
+        let comets = CometElements::choose(
+            CometElements::read_file(path)?,
+            &config.imported_comets(),
+        )?;
+
+        if let Some(maximum) =
+            config.maximum_number_of_comets()
+            && comets.len() > maximum as usize
+        {
+            return Err(
+                SpaceError::TooManyImportedComets {
+                    count: comets.len(),
+                    maximum,
+                },
+            );
+        }
+
+        Ok(comets
+            .into_iter()
+            .map(|comet| {
+                let state = comet.state_vectors(
+                    sun_mass,
+                    config.start_julian_date(),
+                );
+
+                (comet.designation, state)
+            })
+            .collect())
+    }
+
+    /// Получение данных для планет и их спутников
+    fn get_planets_data(
+        planets_data: Box<[PlanetData]>,
+        generate_trajectory_color: &mut impl FnMut() -> Color,
+        sun_mass: Quantity<Kilograms>,
+        time_since_epoch: Quantity<Seconds>,
+        horizons_states: Option<&HorizonsStates>,
+        path_to_images: &str,
+        object_ids: &mut ObjectIdGenerator,
+    ) -> Vec<Planet> {
+        planets_data.into_iter().map(|planet_data| {
+            let PlanetData {
+                name,
+                consts: planet_consts,
+                orbital_elements,
+                image_filename: planet_image_filename,
+                satellites
+            } = planet_data;
+
+            let planet_id = object_ids.generate();
+
+            // Генерация цвета планеты
+            let planet_trajectory_color =
+                generate_trajectory_color();
+
+            let planet_mass =
+                Quantity::new(Kilograms::new(planet_consts.mass));
+
+            // Начальный вектор состояния планеты относительно Солнца:
+            // из таблиц Horizons, либо по элементам орбиты
+            let planet_initial_state = horizons_states
+                .and_then(|states| states.get(&name))
+                .cloned()
+                .unwrap_or_else(|| {
+                    OrbitalElements::from_data(
+                        orbital_elements.as_ref(),
+                        planet_consts.orbit,
+                    )
+                    .state_vectors(
+                        (sun_mass + planet_mass).parse(),
+                        time_since_epoch,
+                    )
+                });
+
+            // Создание спутников
+            let satellites = satellites.into_iter()
+                .map(|satellite_data| {
+                    let satellite_trajectory_color =
+                        generate_trajectory_color();
+
+                    let satellite_mass = Quantity::new(
+                        Kilograms::new(satellite_data.consts.mass),
+                    );
+
+                    // Начальный вектор состояния спутника:
+                    // из таблиц Horizons, либо по элементам орбиты вокруг планеты
+                    let satellite_initial_state = horizons_states
+                        .and_then(|states| states.get(&satellite_data.name))
+                        .cloned()
+                        .unwrap_or_else(|| {
+                            OrbitalElements::from_data(
+                                satellite_data.orbital_elements.as_ref(),
+                                satellite_data.consts.orbit,
+                            )
+                            .state_vectors(
+                                (planet_mass + satellite_mass).parse(),
+                                time_since_epoch,
+                            )
+                            .relative_to(&planet_initial_state)
+                        });
+
+                    Satellite::new(
+                        object_ids.generate(),
+                        satellite_data,
+                        satellite_initial_state,
+                        satellite_trajectory_color,
+                        path_to_images,
+                    )
+                })
+                .collect::<Vec<Satellite>>();
+
+            Planet::new(
+                planet_id,
+                name,
+                planet_consts,
+                planet_initial_state,
+                planet_trajectory_color,
+                format!("{path_to_images}/{planet_image_filename}").into_boxed_str(),
+                satellites,
+            )
+        })
+            .collect::<Vec<Planet>>()
+    }
+}
+
+impl SpaceState {
+    /// Текущий вектор состояния на орбите вокруг Солнца
+    pub fn state_on_orbit(
+        &self,
+        passage: &PerihelionPassage,
+    ) -> StateVectors {
+        passage.state_vectors(
+            &self.sun_state(),
+            self.sun.borrow().mass(),
+        )
+    }
+
+    /// Текущий вектор состояния Солнца
+    pub fn sun_state(&self) -> StateVectors {
+        let sun = self.sun.borrow();
+
+        StateVectors {
+            position: sun.position(),
+            velocity: sun.movement().velocity(),
+        }
+    }
+}
+
+impl SpaceState {
+    pub fn planets(&self) -> &[Rc<RefCell<Planet>>] {
+        self.planets.as_slice()
+    }
+
+    pub fn comets(&self) -> &[Rc<RefCell<Comet>>] {
+        self.comets.as_slice()
+    }
+
+    pub fn comet_possible_values(
+        &self,
+    ) -> &CometPossibleValues {
+        self.comets.possible_values()
+    }
+
+    pub fn comets_count(&self) -> usize {
+        self.comets.count()
+    }
+
+    pub fn all_objects(
+        &self,
+    ) -> &[Weak<RefCell<dyn Object>>] {
+        self.all_objects.as_slice()
+    }
+
+    pub fn moving_objects(
+        &self,
+    ) -> &[Weak<RefCell<dyn MovingObject>>] {
+        self.moving_objects.as_slice()
+    }
+}
+
+impl SpaceState {
+    /// Центр масс всех объектов
+    pub fn barycenter(
+        &self,
+    ) -> Point<Quantity<Kilometers>> {
+        // Пробные частицы не влияют на центр масс
+        let (total_mass, mass_moment) = self
+            .all_objects
+            .iter()
+            .filter_map(Weak::upgrade)
+            .filter(|object| {
+                !object.borrow().is_test_particle()
+            })
+            .fold(
+                (0., Vector::new(0., 0.)),
+                |(total_mass, mass_moment), object| {
+                    let object = object.borrow();
+                    let mass = object.mass().value();
+                    let Point { x, y } = object.position();
+
+                    (
+                        total_mass + mass,
+                        mass_moment
+                            + Vector::new(
+                                x.value(),
+                                y.value(),
+                            ) * mass,
+                    )
+                },
+            );
+
+        Point {
+            x: Quantity::new(Kilometers::new(
+                mass_moment.x / total_mass,
+            )),
+            y: Quantity::new(Kilometers::new(
+                mass_moment.y / total_mass,
+            )),
+        }
+    }
+
+    /// Перенос двигающихся объектов в барицентрическую систему отсчёта
+    /// с нулевым суммарным импульсом
+    fn move_to_barycentric_frame(&self) {
+        let moving_objects = self
+            .moving_objects
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect::<Vec<_>>();
+
+        let (total_mass, mass_moment, momentum) =
+            moving_objects.iter().fold(
+                (
+                    0.,
+                    Vector::new(0., 0.),
+                    Vector::new(0., 0.),
+                ),
+                |(total_mass, mass_moment, momentum),
+                 object| {
+                    let object = object.borrow();
+
+                    // Пробные частицы не влияют на центр масс и импульс
+                    if object.is_test_particle() {
+                        return (
+                            total_mass,
+                            mass_moment,
+                            momentum,
+                        );
+                    }
+
+                    let mass = object.mass().value();
+                    let Point { x, y } = object.position();
+
+                    (
+                        total_mass + mass,
+                        mass_moment
+                            + Vector::new(
+                                x.value(),
+                                y.value(),
+                            ) * mass,
+                        momentum
+                            + object
+                                .movement()
+                                .velocity()
+                                .to_vector()
+                                * mass,
+                    )
+                },
+            );
+
+        // Положение и скорость центра масс
+        let barycenter = mass_moment / total_mass;
+        let barycenter_velocity = momentum / total_mass;
+
+        moving_objects.iter().for_each(|object| {
+            object.borrow_mut().movement_mut().shift_frame(
+                -barycenter,
+                -barycenter_velocity,
+            );
+        });
+    }
+}
+
+impl SpaceState {
+    /// Движение объектов.
+    ///
+    /// При отрицательном `seconds_per_tick` объекты движутся назад во времени
+    pub fn move_objects(
+        &mut self,
+        seconds_per_tick: Quantity<Seconds>,
+        integrator: &dyn Integrator,
+        step_control: StepControl,
+    ) {
+        // Двигающиеся объекты
+        let moving_objects = self
+            .moving_objects
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect::<Vec<_>>();
+
+        // Идентификаторы двигающихся объектов
+        let moving_objects_ids = moving_objects
+            .iter()
+            .map(|object| object.borrow().id())
+            .collect::<HashSet<ObjectId>>();
+
+        // Двигающиеся тела идут первыми, в порядке двигающихся объектов
+        let mut bodies = moving_objects
+            .iter()
+            .map(|object| {
+                let object = object.borrow();
+                let values =
+                    object.gravitational_force_values();
+                let velocity = object.movement().velocity();
+
+                if object.is_test_particle() {
+                    Body::test_particle(values, velocity)
+                } else {
+                    Body::moving(values, velocity)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Неподвижные объекты, только притягивающие остальные
+        bodies.extend(
+            self.all_objects
+                .iter()
+                .filter_map(Weak::upgrade)
+                .filter(|object| {
+                    !moving_objects_ids
+                        .contains(&object.borrow().id())
+                })
+                .map(|object| {
+                    Body::fixed(
+                        object
+                            .borrow()
+                            .gravitational_force_values(),
+                    )
+                }),
+        );
+
+        let mut system = GravitationalSystem::new(bodies);
+
+        // Тик дробится на подшаги при тесных сближениях,
+        // траектория же пополняется один раз за тик
+        step_control.integrate(
+            integrator,
+            &mut system,
+            seconds_per_tick,
+        );
+
+        // Запись новых позиций и скоростей
+        moving_objects
+            .iter()
+            .zip(system.bodies())
+            .for_each(|(object, body)| {
+                let mut object = object.borrow_mut();
+
+                if self.is_recording_trajectories {
+                    object.update_position(
+                        body.position(),
+                        body.velocity(),
+                    );
+                } else {
+                    object.movement_mut().update_state(
+                        body.position(),
+                        body.velocity(),
+                    );
+                }
+            });
+    }
+
+    /// Включение или отключение записи траекторий
+    pub fn set_recording_trajectories(
+        &mut self,
+        is_recording: bool,
+    ) {
+        self.is_recording_trajectories = is_recording;
+    }
+}
+
+impl SpaceState {
+    /// Удаление комет, столкнувшихся с Солнцем, планетой или спутником,
+    /// кометы друг с другом не сталкиваются
+    pub fn remove_crashed_comets(&mut self) {
+        // Получение тел как кругов
+        let circles = self
+            .planets
+            .iter()
+            .flat_map(|planet| {
+                let planet = planet.borrow();
+
+                planet
+                    .satellites()
+                    .iter()
+                    .map(|satellite| {
+                        Circle::from(
+                            satellite
+                                .borrow()
+                                .form_values(),
+                        )
+                    })
+                    .chain([Circle::from(
+                        planet.form_values(),
+                    )])
+                    .collect::<Vec<_>>()
+            })
+            .chain([Circle::from(
+                self.sun.borrow().form_values(),
+            )])
+            .collect::<Vec<_>>();
+
+        // Удаление комет, которые столкнулись с телами
+        let removed_count = self.comets.retain(|comet| {
+            let comet_circle =
+                Circle::from(comet.form_values());
+
+            !circles.iter().any(|circle| {
+                is_circles_have_common_points(
+                    &comet_circle,
+                    circle,
+                )
+            })
+        });
+
+        if removed_count > 0 {
+            self.filter_from_cleared_objects();
+            self.filter_from_cleared_moving_objects();
+        }
+    }
+}
+
+impl SpaceState {
+    /// Создание и добавление новой кометы в свободной позиции
+    pub fn add_new_comet(
+        &mut self,
+        rng: &mut ChaCha12Rng,
+    ) -> Result<(), SpawnError> {
+        self.check_comets_count()?;
+
+        let initial_state = self.sample_free_state(
+            self.maximum_comet_radius(),
+            || {
+                Comet::generate_initial_state(
+                    self.comets.possible_values(),
+                    rng,
+                )
+            },
+        )?;
+
+        self.add_new_comet_with_state(initial_state, rng)
+    }
+
+    /// Создание и добавление новой кометы
+    /// с заданным начальным вектором состояния
+    pub fn add_new_comet_with_state(
+        &mut self,
+        initial_state: StateVectors,
+        rng: &mut ChaCha12Rng,
+    ) -> Result<(), SpawnError> {
+        self.check_comets_count()?;
+        self.check_spawn_position(
+            &initial_state,
+            self.maximum_comet_radius(),
+        )?;
+
+        let new_comet =
+            self.comets.add_new_comet_with_state(
+                &mut self.object_ids,
+                initial_state,
+                rng,
+            );
+        self.push_comet_object(&new_comet);
+
+        Ok(())
+    }
+
+    /// Создание и добавление новой кометы по профилю появления
+    /// в свободной позиции
+    pub fn add_new_comet_from_profile(
+        &mut self,
+        profile_index: usize,
+        rng: &mut ChaCha12Rng,
+    ) -> Result<(), SpawnError> {
+        self.check_comets_count()?;
+
+        let profile = self
+            .comets
+            .possible_values()
+            .spawn_profiles()
+            .get(profile_index)
+            .expect("Spawn profile must exist");
+
+        let initial_state = self.sample_free_state(
+            self.maximum_comet_radius(),
+            || {
+                self.state_on_orbit(
+                    &profile.generate_passage(rng),
+                )
+            },
+        )?;
+
+        self.add_new_comet_with_state(initial_state, rng)
+    }
+
+    /// Добавление кометы с заданными параметрами орбиты:
+    /// расстояние в перигелии и эксцентриситет выдерживаются
+    /// при любом наклонении, оно задаёт только направление обращения
+    pub fn add_comet_on_orbit(
+        &mut self,
+        parameters: CometOrbitParameters,
+        rng: &mut ChaCha12Rng,
+    ) -> Result<(), SpawnError> {
+        let CometOrbitParameters { passage, mass, radius } =
+            parameters;
+
+        self.add_comet_with_parameters(
+            CometParameters {
+                initial_state: self
+                    .state_on_orbit(&passage),
+                mass,
+                radius,
+            },
+            rng,
+        )
+    }
+
+    /// Добавление кометы с заданными параметрами
+    pub fn add_comet_with_parameters(
+        &mut self,
+        parameters: CometParameters,
+        rng: &mut ChaCha12Rng,
+    ) -> Result<(), SpawnError> {
+        self.check_comets_count()?;
+        self.check_spawn_position(
+            &parameters.initial_state,
+            parameters.radius,
+        )?;
+
+        let new_comet =
+            self.comets.add_comet_with_parameters(
+                &mut self.object_ids,
+                parameters,
+                rng,
+            );
+        self.push_comet_object(&new_comet);
+
+        Ok(())
+    }
+
+    /// Наибольший радиус случайной кометы
+    fn maximum_comet_radius(&self) -> Quantity<Kilometers> {
+        Quantity::new(Kilometers::new(
+            self.comets.possible_values().radii().1,
+        ))
+    }
+
+    /// Добавление импортированных комет в начальном состоянии
+    fn add_imported_comets(
+        &mut self,
+        rng: &mut ChaCha12Rng,
+    ) {
+        self.imported_comets.iter().for_each(
+            |(name, initial_state)| {
+                let comet = Rc::downgrade(
+                    &self.comets.add_comet_with_state(
+                        &mut self.object_ids,
+                        name.clone(),
+                        initial_state.clone(),
+                        rng,
+                    ),
+                );
+                self.all_objects.push(comet.clone());
+                self.moving_objects.push(comet);
+            },
+        );
+    }
+
+    /// Добавление кометы в списки всех и движущихся объектов
+    fn push_comet_object(
+        &mut self,
+        comet: &Rc<RefCell<Comet>>,
+    ) {
+        let comet = Rc::downgrade(comet);
+        self.all_objects.push(comet.clone());
+        self.moving_objects.push(comet);
+    }
+
+    /// Удаление кометы
+    pub fn delete_comet(&mut self, id: ObjectId) {
+        if self.comets.delete_comet(id) {
+            self.filter_from_cleared_objects();
+            self.filter_from_cleared_moving_objects();
+        }
+    }
+}
+
+impl SpaceState {
+    /// Копия космических объектов по снимку без чтения файлов данных.
+    /// Импортированных комет в ней нет, поэтому перезагружать её нельзя
+    pub fn from_snapshot(
+        snapshot: SpaceSnapshot,
+        comet_possible_values: CometPossibleValues,
+        maximum_number_of_comets: Option<u32>,
+    ) -> Self {
+        let SpaceSnapshot {
+            object_ids,
+            is_sun_moving,
+            sun,
+            planets,
+            comets,
+        } = snapshot;
+
+        let sun =
+            Rc::new(RefCell::new(Sun::from_snapshot(sun)));
+
+        let planets = planets
+            .into_iter()
+            .map(|planet| {
+                Rc::new(RefCell::new(
+                    Planet::from_snapshot(planet),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let all_objects =
+            Self::get_all_objects(&sun, planets.as_slice());
+
+        // Цвета Солнца, планет и спутников не достаются кометам
+        let trajectory_colors = Self::get_moving_objects(
+            &sun,
+            planets.as_slice(),
+            true,
+        )
+        .iter()
+        .filter_map(Weak::upgrade)
+        .map(|object| object.borrow().trajectory_color())
+        .collect();
+
+        let moving_objects = Self::get_moving_objects(
+            &sun,
+            planets.as_slice(),
+            is_sun_moving,
+        );
+
+        let mut space = Self {
+            planets,
+            sun,
+            all_objects,
+            moving_objects,
+            comets: CometsState::new(
+                comet_possible_values,
+                trajectory_colors,
+                maximum_number_of_comets,
+                comets.are_test_particles,
+            ),
+            imported_comets: Vec::new(),
+            object_ids,
+            is_sun_moving,
+            is_recording_trajectories: true,
+        };
+
+        let comets = space
+            .comets
+            .restore(comets)
+            .iter()
+            .map(Rc::downgrade)
+            .collect::<Vec<_>>();
+
+        comets.into_iter().for_each(|comet| {
+            space.all_objects.push(comet.clone());
+            space.moving_objects.push(comet);
+        });
+
+        space
+    }
+}
+
+impl SpaceState {
+    /// Снимок всех космических объектов,
+    /// без траекторий они начинаются заново с текущих позиций
+    pub fn snapshot(
+        &self,
+        with_trajectories: bool,
+    ) -> SpaceSnapshot {
+        SpaceSnapshot {
+            object_ids: self.object_ids.clone(),
+            is_sun_moving: self.is_sun_moving,
+            sun: BodySnapshot::of(
+                &*self.sun.borrow(),
+                with_trajectories,
+            ),
+            planets: self
+                .planets
+                .iter()
+                .map(|planet| {
+                    let planet = planet.borrow();
+                    PlanetSnapshot {
+                        body: BodySnapshot::of(
+                            &*planet,
+                            with_trajectories,
+                        ),
+                        satellites: planet
+                            .satellites()
+                            .iter()
+                            .map(|satellite| {
+                                BodySnapshot::of(
+                                    &*satellite.borrow(),
+                                    with_trajectories,
+                                )
+                            })
+                            .collect(),
+                    }
+                })
+                .collect(),
+            comets: self.comets.snapshot(with_trajectories),
+        }
+    }
+
+    /// Проверка, что снимок можно восстановить: тела снимка
+    /// должны совпадать с данными Солнечной системы
+    pub fn check_snapshot(
+        &self,
+        snapshot: &SpaceSnapshot,
+    ) -> Result<(), SnapshotError> {
+        let SpaceSnapshot { sun, planets, comets, .. } =
+            snapshot;
+
+        let is_planets_matching = planets.len()
+            == self.planets.len()
+            && planets.iter().zip(&self.planets).all(
+                |(planet_snapshot, planet)| {
+                    let planet = planet.borrow();
+                    planet_snapshot.body.id == planet.id()
+                        && planet_snapshot
+                            .satellites
+                            .iter()
+                            .map(|satellite| satellite.id)
+                            .eq(planet
+                                .satellites()
+                                .iter()
+                                .map(|satellite| {
+                                    satellite.borrow().id()
+                                }))
+                },
+            );
+
+        if sun.id != self.sun.borrow().id()
+            || !is_planets_matching
+        {
+            return Err(SnapshotError::MismatchedBodies);
+        }
+
+        self.comets.check_snapshot(comets)
+    }
+
+    /// Восстановление из снимка, тела снимка
+    /// должны совпадать с данными Солнечной системы
+    pub fn restore(
+        &mut self,
+        snapshot: SpaceSnapshot,
+    ) -> Result<(), SnapshotError> {
+        // Проверка до изменения состояния
+        self.check_snapshot(&snapshot)?;
+
+        let SpaceSnapshot {
+            object_ids,
+            is_sun_moving,
+            sun,
+            planets,
+            comets,
+        } = snapshot;
+
+        self.sun.borrow_mut().restore(sun);
+
+        planets.into_iter().zip(&self.planets).for_each(
+            |(planet_snapshot, planet)| {
+                let mut planet = planet.borrow_mut();
+                planet_snapshot
+                    .satellites
+                    .into_iter()
+                    .zip(planet.satellites())
+                    .for_each(|(snapshot, satellite)| {
+                        satellite
+                            .borrow_mut()
+                            .restore(snapshot)
+                    });
+                planet.restore(planet_snapshot.body);
+            },
+        );
+
+        let comets = self
+            .comets
+            .restore(comets)
+            .iter()
+            .map(Rc::downgrade)
+            .collect::<Vec<_>>();
+
+        self.filter_from_cleared_objects();
+
+        // Солнце двигается или нет, как в снимке
+        self.is_sun_moving = is_sun_moving;
+        self.moving_objects = Self::get_moving_objects(
+            &self.sun,
+            self.planets.as_slice(),
+            is_sun_moving,
+        );
+
+        comets.into_iter().for_each(|comet| {
+            self.all_objects.push(comet.clone());
+            self.moving_objects.push(comet);
+        });
+
+        self.object_ids = object_ids;
+
+        Ok(())
+    }
+}
+
+impl SpaceState {
+    /// Изменение объектов с прошлой публикации,
+    /// количество переданных позиций траекторий при этом обновляется
+    pub fn update_since(
+        &self,
+        published: &mut PublishedPositions,
+    ) -> SpaceUpdate {
+        let mut next_published = PublishedPositions::new();
+
+        let mut movement_update =
+            |object: &dyn MovingObject| {
+                let movement = object.movement();
+                next_published.insert(
+                    object.id(),
+                    movement.added_positions_count(),
+                );
+
+                movement.update_since(
+                    published
+                        .get(&object.id())
+                        .copied()
+                        .unwrap_or_default(),
+                )
+            };
+
+        let sun = movement_update(&*self.sun.borrow());
+
+        let planets = self
+            .planets
+            .iter()
+            .map(|planet| {
+                let planet = planet.borrow();
+
+                PlanetUpdate {
+                    body: movement_update(&*planet),
+                    satellites: planet
+                        .satellites()
+                        .iter()
+                        .map(|satellite| {
+                            movement_update(
+                                &*satellite.borrow(),
+                            )
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        // Кометы, которых ещё нет в копии, передаются целиком
+        let comets = self
+            .comets
+            .as_slice()
+            .iter()
+            .map(|comet| {
+                let comet = comet.borrow();
+
+                if published.contains_key(&comet.id()) {
+                    CometUpdate::Existing {
+                        id: comet.id(),
+                        movement: movement_update(&*comet),
+                    }
+                } else {
+                    movement_update(&*comet);
+                    CometUpdate::New(BodySnapshot::of(
+                        &*comet, true,
+                    ))
+                }
+            })
+            .collect();
+
+        *published = next_published;
+
+        SpaceUpdate {
+            object_ids: self.object_ids.clone(),
+            sun,
+            planets,
+            comets: CometsUpdate {
+                new_comet_number: self
+                    .comets
+                    .new_comet_number(),
+                comets,
+            },
+        }
+    }
+
+    /// Отметка всех объектов как переданных копии целиком
+    pub fn mark_published(
+        &self,
+        published: &mut PublishedPositions,
+    ) {
+        published.clear();
+
+        let mut mark = |object: &dyn MovingObject| {
+            published.insert(
+                object.id(),
+                object.movement().added_positions_count(),
+            );
+        };
+
+        mark(&*self.sun.borrow());
+        self.planets.iter().for_each(|planet| {
+            let planet = planet.borrow();
+            mark(&*planet);
+            planet.satellites().iter().for_each(
+                |satellite| mark(&*satellite.borrow()),
+            );
+        });
+        self.comets
+            .as_slice()
+            .iter()
+            .for_each(|comet| mark(&*comet.borrow()));
+    }
+
+    /// Применение изменения к копии космических объектов
+    pub fn apply_update(&mut self, update: SpaceUpdate) {
+        let SpaceUpdate {
+            object_ids,
+            sun,
+            planets,
+            comets,
+        } = update;
+
+        self.sun
+            .borrow_mut()
+            .movement_mut()
+            .apply_update(sun);
+
+        planets.into_iter().zip(&self.planets).for_each(
+            |(planet_update, planet)| {
+                let mut planet = planet.borrow_mut();
+                planet_update
+                    .satellites
+                    .into_iter()
+                    .zip(planet.satellites())
+                    .for_each(|(update, satellite)| {
+                        satellite
+                            .borrow_mut()
+                            .movement_mut()
+                            .apply_update(update)
+                    });
+                planet
+                    .movement_mut()
+                    .apply_update(planet_update.body);
+            },
+        );
+
+        let new_comets = self.comets.apply_update(comets);
+
+        self.filter_from_cleared_objects();
+        self.filter_from_cleared_moving_objects();
+
+        new_comets.iter().for_each(|comet| {
+            self.push_comet_object(comet)
+        });
+
+        self.object_ids = object_ids;
+    }
+}
+
+impl SpaceState {
+    // При перезагрузке симуляции
+    pub fn reload(&mut self, rng: &mut ChaCha12Rng) {
+        self.comets.reload();
+        self.filter_from_cleared_objects();
+        self.filter_from_cleared_moving_objects();
+        self.sun.borrow_mut().reload();
+        self.reload_planets_and_satellites();
+        self.add_imported_comets(rng);
+
+        if self.is_sun_moving {
+            self.move_to_barycentric_frame();
+        }
+    }
+
+    // Очищаем удалённые объекты
+    fn filter_from_cleared_objects(&mut self) {
+        self.all_objects
+            .retain(|object| object.strong_count() > 0);
+    }
+
+    // Очищаем удалённые движущиеся объекты
+    fn filter_from_cleared_moving_objects(&mut self) {
+        self.moving_objects
+            .retain(|object| object.strong_count() > 0);
+    }
+
+    // Возвращаем планеты и спутники в начальное состояние
+    fn reload_planets_and_satellites(&mut self) {
+        self.planets.iter().for_each(|planet| {
+            let mut planet = planet.borrow_mut();
+            planet.reload();
+            planet.satellites().iter().for_each(
+                |satellite| satellite.borrow_mut().reload(),
+            );
+        });
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{
+    space::SpaceState,
+    util::{
+        geometry::circle::{
+            Circle, is_circles_have_common_points,
+        },
+        objects::{Object, values::ObjectFormValues},
+        physics::{
+            formulas::hill_radius,
+            orbit::StateVectors,
+            quantities::{
+                Quantity, point_without_quantity_units,
+                quantity_units::Kilometers,
+            },
+        },
+    },
+};
+
+/// Количество попыток выбрать свободную начальную позицию
+pub const MAXIMUM_SPAWN_ATTEMPTS: u16 = 100;
+
+/// Ошибка добавления новой кометы
+#[derive(Debug, Clone)]
+pub enum SpawnError {
+    /// Уже добавлено максимальное количество комет
+    TooManyComets { maximum: u32 },
+    /// Начальная позиция внутри тела или сферы Хилла планеты
+    InsideBody { name: Box<str> },
+    /// Не найдена свободная начальная позиция
+    NoFreePosition { attempts: u16 },
+}
+
+impl Display for SpawnError {
+    fn fmt(
+        &self,
+        fmt: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Self::TooManyComets { maximum } => write!(
+                fmt,
+                "достигнуто максимальное количество комет ({maximum})"
+            ),
+            Self::InsideBody { name } => write!(
+                fmt,
+                "начальная позиция внутри объекта {name} \
+                 или его сферы Хилла"
+            ),
+            Self::NoFreePosition { attempts } => write!(
+                fmt,
+                "свободная начальная позиция не найдена \
+                 за {attempts} попыток"
+            ),
+        }
+    }
+}
+
+impl SpaceState {
+    /// Проверка, что можно добавить ещё одну комету
+    pub(super) fn check_comets_count(
+        &self,
+    ) -> Result<(), SpawnError> {
+        match self.comets.maximum_number_of_comets() {
+            Some(maximum) if self.comets.is_full() => {
+                Err(SpawnError::TooManyComets { maximum })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Проверка, что комета заданного радиуса в начальной позиции
+    /// не пересекает тела и сферы Хилла планет
+    pub fn check_spawn_position(
+        &self,
+        initial_state: &StateVectors,
+        radius: Quantity<Kilometers>,
+    ) -> Result<(), SpawnError> {
+        let comet_circle =
+            Circle::from(ObjectFormValues::new(
+                initial_state.position,
+                radius,
+            ));
+
+        let sun = self.sun.borrow();
+        let sun_position =
+            point_without_quantity_units(sun.position());
+
+        // Сферы Хилла планет относительно Солнца
+        let planets = self.planets.iter().map(|planet| {
+            let planet = planet.borrow();
+            let position = point_without_quantity_units(
+                planet.position(),
+            );
+            let distance = (position.x - sun_position.x)
+                .hypot(position.y - sun_position.y);
+
+            (
+                Box::<str>::from(planet.name()),
+                Circle::new(
+                    position,
+                    hill_radius(
+                        distance,
+                        planet.mass(),
+                        sun.mass(),
+                    )
+                    .max(planet.radius().value()),
+                ),
+            )
+        });
+
+        // Сами тела, включая Солнце, спутники и массивные кометы,
+        // пробные частицы друг другу не мешают
+        let objects = self
+            .all_objects
+            .iter()
+            .filter_map(|object| object.upgrade())
+            .filter(|object| {
+                !object.borrow().is_test_particle()
+            })
+            .map(|object| {
+                let object = object.borrow();
+
+                (
+                    Box::<str>::from(object.name()),
+                    Circle::from(object.form_values()),
+                )
+            });
+
+        match planets.chain(objects).find(|(_, circle)| {
+            is_circles_have_common_points(
+                &comet_circle,
+                circle,
+            )
+        }) {
+            Some((name, _)) => {
+                Err(SpawnError::InsideBody { name })
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Выбор случайного начального вектора состояния
+    /// в свободной позиции
+    pub(super) fn sample_free_state(
+        &self,
+        radius: Quantity<Kilometers>,
+        mut generate_state: impl FnMut() -> StateVectors,
+    ) -> Result<StateVectors, SpawnError> {
+        (0..MAXIMUM_SPAWN_ATTEMPTS)
+            .map(|_| generate_state())
+            .find(|initial_state| {
+                self.check_spawn_position(
+                    initial_state,
+                    radius,
+                )
+                .is_ok()
+            })
+            .ok_or(SpawnError::NoFreePosition {
+                attempts: MAXIMUM_SPAWN_ATTEMPTS,
+            })
+    }
+}
@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use rand::{RngExt, rngs::ChaCha12Rng};
+use serde::{Deserialize, Serialize};
+
+/// Цвет траектории объекта
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub struct Color {
+    /// Красная составляющая
+    pub r: u8,
+    /// Зелёная составляющая
+    pub g: u8,
+    /// Синяя составляющая
+    pub b: u8,
+}
+
+impl Color {
+    #[inline(always)]
+    pub const fn from_rgb8(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+}
+
+impl Color {
+    /// Генерация случайного цвета, не совпадающего с занятыми,
+    /// новый цвет становится занятым
+    pub fn generate_unique(
+        taken_colors: &mut HashSet<Color>,
+        rng: &mut ChaCha12Rng,
+    ) -> Self {
+        loop {
+            let color = Self::from_rgb8(
+                rng.random_range(0..255_u8),
+                rng.random_range(0..255_u8),
+                rng.random_range(0..255_u8),
+            );
+
+            if taken_colors.insert(color) {
+                return color;
+            }
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use std::{
+    fmt::{Display, Formatter},
+    fs::read_to_string,
+    io,
+    path::PathBuf,
+};
+
+use crate::util::{
+    physics::{
+        orbit::{PerihelionOrbit, StateVectors},
+        quantities::{
+            Quantity,
+            quantity_units::{
+                Kilograms, Kilometers, Seconds,
+            },
+        },
+    },
+    time::julian_date,
+};
+
+/// Астрономическая единица в км
+const ASTRONOMICAL_UNIT: f64 = 149_597_870.7;
+/// Секунд в сутках
+const SECONDS_PER_DAY: f64 = 86_400.;
+
+/// Элементы орбиты кометы из файла `CometEls.txt` Центра малых планет
+pub struct CometElements {
+    /// Обозначение и название
+    pub designation: Box<str>,
+    /// Момент прохождения перигелия (юлианская дата)
+    pub perihelion_time: f64,
+    /// Перигелийное расстояние (в а. е.)
+    pub perihelion_distance: f64,
+    /// Эксцентриситет
+    pub eccentricity: f64,
+    /// Аргумент перигелия (в градусах)
+    pub argument_of_perihelion: f64,
+    /// Долгота восходящего узла (в градусах)
+    pub longitude_of_ascending_node: f64,
+    /// Наклонение (в градусах)
+    pub inclination: f64,
+}
+
+impl CometElements {
+    /// Чтение всех комет из файла
+    pub fn read_file(
+        path: &str,
+    ) -> Result<Vec<Self>, CometElementsError> {
+        read_to_string(path)
+            .map_err(CometElementsError::Io)
+            .and_then(|text| {
+                text.lines()
+                    .enumerate()
+                    .filter(|(_, line)| !line.trim().is_empty())
+                    .map(|(index, line)| {
+                        Self::parse_line(line).map_err(
+                            |field| {
+                                CometElementsError::MalformedField {
+                                    line: index + 1,
+                                    field,
+                                    text: line.trim().into(),
+                                }
+                            },
+                        )
+                    })
+                    .collect()
+            })
+            .map_err(|error| CometElementsError::InFile {
+                path: path.into(),
+                error: Box::new(error),
+            })
+    }
+
+    /// Выбор комет по обозначениям в заданном порядке
+    pub fn choose(
+        comets: Vec<Self>,
+        designations: &[&str],
+    ) -> Result<Vec<Self>, CometElementsError> {
+        let mut comets = comets
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+
+        designations
+            .iter()
+            .map(|designation| {
+                comets
+                    .iter_mut()
+                    .find(|comet| {
+                        comet.as_ref().is_some_and(
+                            |comet| {
+                                comet.is_designated(
+                                    designation,
+                                )
+                            },
+                        )
+                    })
+                    .and_then(Option::take)
+                    .ok_or_else(|| {
+                        CometElementsError::NotFound(
+                            (*designation).into(),
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// Соответствует ли комета обозначению: полному (`1P/Halley`)
+    /// или без названия (`1P`, `C/1995 O1`)
+    fn is_designated(&self, designation: &str) -> bool {
+        let designation = designation.trim();
+        let full = self.designation.as_ref();
+
+        if full.eq_ignore_ascii_case(designation) {
+            return true;
+        }
+
+        // Обозначение, за которым следует название
+        full.get(..designation.len()).is_some_and(
+            |prefix| {
+                prefix.eq_ignore_ascii_case(designation)
+            },
+        ) && {
+            let name = &full[designation.len()..];
+            name.starts_with('/') || name.starts_with(" (")
+        }
+    }
+}
+
+impl CometElements {
+    /// Разбор строки фиксированной ширины, при ошибке возвращается название поля
+    fn parse_line(
+        line: &str,
+    ) -> Result<Self, &'static str> {
+        let year =
+            parse_field(line, 15, 18, "perihelion year")?;
+        let month =
+            parse_field(line, 20, 21, "perihelion month")?;
+        let day =
+            parse_field(line, 23, 29, "perihelion day")?;
+
+        let designation = field(line, 103, 158);
+
+        if designation.is_empty() {
+            return Err("designation");
+        }
+
+        Ok(Self {
+            designation: designation.into(),
+            perihelion_time: julian_date(year, month, day),
+            perihelion_distance: parse_field(
+                line,
+                31,
+                39,
+                "perihelion distance",
+            )?,
+            eccentricity: parse_field(
+                line,
+                42,
+                49,
+                "eccentricity",
+            )?,
+            argument_of_perihelion: parse_field(
+                line,
+                52,
+                59,
+                "argument of perihelion",
+            )?,
+            longitude_of_ascending_node: parse_field(
+                line,
+                62,
+                69,
+                "longitude of ascending node",
+            )?,
+            inclination: parse_field(
+                line,
+                72,
+                79,
+                "inclination",
+            )?,
+        })
+    }
+}
+
+impl CometElements {
+    /// Орбита кометы
+    pub fn orbit(&self) -> PerihelionOrbit {
+        PerihelionOrbit::new(
+            Quantity::new(Kilometers::new(
+                self.perihelion_distance
+                    * ASTRONOMICAL_UNIT,
+            )),
+            self.eccentricity,
+            self.argument_of_perihelion,
+            self.longitude_of_ascending_node,
+            self.inclination,
+        )
+    }
+
+    /// Вектор состояния относительно Солнца на юлианскую дату
+    pub fn state_vectors(
+        &self,
+        sun_mass: Quantity<Kilograms>,
+        julian_date: f64,
+    ) -> StateVectors {
+        self.orbit().state_vectors(
+            sun_mass,
+            Quantity::new(Seconds::new(
+                (julian_date - self.perihelion_time)
+                    * SECONDS_PER_DAY,
+            )),
+        )
+    }
+}
+
+/// Поле строки по номерам столбцов (с единицы, включительно)
+fn field(line: &str, start: usize, end: usize) -> &str {
+    let end = end.min(line.len());
+
+    line.get(start - 1..end).unwrap_or_default().trim()
+}
+
+/// Разбор числового поля строки
+fn parse_field<T: std::str::FromStr>(
+    line: &str,
+    start: usize,
+    end: usize,
+    name: &'static str,
+) -> Result<T, &'static str> {
+    field(line, start, end).parse().map_err(|_| name)
+}
+
+/// Ошибка импорта элементов орбит комет
+#[derive(Debug)]
+pub enum CometElementsError {
+    /// Ошибка чтения
+    Io(io::Error),
+    /// Некорректное поле строки
+    MalformedField {
+        line: usize,
+        field: &'static str,
+        text: String,
+    },
+    /// Комета не найдена
+    NotFound(String),
+    /// Ошибка в конкретном файле
+    InFile { path: PathBuf, error: Box<CometElementsError> },
+}
+
+impl Display for CometElementsError {
+    fn fmt(
+        &self,
+        fmt: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(fmt, "{error}"),
+            Self::MalformedField { line, field, text } => {
+                write!(
+                    fmt,
+                    "line {line}: malformed {field} in \"{text}\""
+                )
+            }
+            Self::NotFound(designation) => {
+                write!(fmt, "comet {designation} not found")
+            }
+            Self::InFile { path, error } => {
+                write!(fmt, "{}: {error}", path.display())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::physics::orbit::tests::{
+        SUN_MASS, perihelion_and_eccentricity,
+    };
+
+    /// Строка `CometEls.txt` для кометы Галлея
+    const HALLEY: &str = "0001P         1986 02  9.4659  0.585978  0.967143  111.8657   59.3966  162.1951  19860305   4.0  6.0  1P/Halley                                                 98, 1083";
+
+    #[test]
+    fn parses_halley_line() {
+        let comet =
+            CometElements::parse_line(HALLEY).unwrap();
+
+        assert_eq!(comet.designation.as_ref(), "1P/Halley");
+        assert_eq!(comet.perihelion_distance, 0.585978);
+        assert_eq!(comet.eccentricity, 0.967143);
+        assert_eq!(comet.argument_of_perihelion, 111.8657);
+        assert_eq!(
+            comet.longitude_of_ascending_node,
+            59.3966
+        );
+        assert_eq!(comet.inclination, 162.1951);
+        // 1986-02-09.4659 TT
+        assert!(
+            (comet.perihelion_time - 2446470.9659).abs()
+                < 1e-6
+        );
+    }
+
+    #[test]
+    fn keeps_halley_perihelion_in_ecliptic() {
+        let comet =
+            CometElements::parse_line(HALLEY).unwrap();
+
+        // Полгода до перигелия и сам перигелий
+        for days in [-180., 0.] {
+            let state = comet.state_vectors(
+                Quantity::new(Kilograms::new(SUN_MASS)),
+                comet.perihelion_time + days,
+            );
+            let (q, e) = perihelion_and_eccentricity(
+                &state, SUN_MASS,
+            );
+
+            assert!(
+                (q / ASTRONOMICAL_UNIT - 0.585978).abs()
+                    < 1e-9
+            );
+            assert!((e - 0.967143).abs() < 1e-9);
+        }
+
+        // Комета Галлея обращается в обратную сторону
+        assert!(comet.orbit().is_retrograde());
+    }
+
+    #[test]
+    fn chooses_comet_by_designation() {
+        let comets = vec![
+            CometElements::parse_line(HALLEY).unwrap(),
+        ];
+
+        let chosen =
+            CometElements::choose(comets, &["1P"]).unwrap();
+
+        assert_eq!(
+            chosen[0].designation.as_ref(),
+            "1P/Halley"
+        );
+    }
+
+    #[test]
+    fn reports_malformed_field() {
+        let line = HALLEY.replace("0.967143", "0.96x143");
+
+        assert_eq!(
+            CometElements::parse_line(&line).err(),
+            Some("eccentricity")
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    env::var,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// Стуктура для получения данных из конфига
+pub struct ConfigData;
+
+impl ConfigData {
+    pub fn init() {
+        dotenv::from_path("./.env").ok();
+    }
+}
+
+impl ConfigData {
+    /// Получение данных из конфига по ключу
+    #[inline(always)]
+    pub fn get<T: FromStr>(
+        key: &'static str,
+    ) -> Result<T, ConfigError>
+    where
+        <T as FromStr>::Err: Display,
+    {
+        let value = var(key)
+            .map_err(|_| ConfigError::Missing(key))?;
+
+        Self::parse(key, value)
+    }
+
+    /// Получение необязательных данных из конфига по ключу,
+    /// пустое значение считается отсутствующим
+    #[inline(always)]
+    pub fn get_optional<T: FromStr>(
+        key: &'static str,
+    ) -> Result<Option<T>, ConfigError>
+    where
+        <T as FromStr>::Err: Display,
+    {
+        var(key)
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| Self::parse(key, value))
+            .transpose()
+    }
+
+    /// Получение списка значений, разделённых запятыми, из конфига по ключу
+    pub fn get_list(key: &str) -> Vec<Box<str>> {
+        var(key)
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(Box::from)
+            .collect()
+    }
+
+    /// Разбор значения из конфига
+    fn parse<T: FromStr>(
+        key: &'static str,
+        value: String,
+    ) -> Result<T, ConfigError>
+    where
+        <T as FromStr>::Err: Display,
+    {
+        value.parse::<T>().map_err(|error| {
+            ConfigError::Invalid {
+                key,
+                value,
+                error: error.to_string(),
+            }
+        })
+    }
+}
+
+/// Ошибка чтения конфига
+#[derive(Debug, Clone)]
+pub enum ConfigError {
+    /// Значение не задано
+    Missing(&'static str),
+    /// Значение не удалось разобрать
+    Invalid {
+        key: &'static str,
+        value: String,
+        error: String,
+    },
+}
+
+impl Display for ConfigError {
+    fn fmt(
+        &self,
+        fmt: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Self::Missing(key) => {
+                write!(fmt, "config value {key} is not set")
+            }
+            Self::Invalid { key, value, error } => write!(
+                fmt,
+                "invalid config value {key}=\"{value}\": {error}"
+            ),
+        }
+    }
+}
@@ -0,0 +1,813 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    fs::{read_dir, read_to_string},
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::util::{
+    geometry::vector::Vector, physics::orbit::StateVectors,
+};
+
+/// Идентификатор Солнца в Horizons
+const SUN_ID: i64 = 10;
+/// Идентификатор барицентра Солнечной системы в Horizons
+const SOLAR_SYSTEM_BARYCENTER_ID: i64 = 0;
+/// Астрономическая единица в км
+const ASTRONOMICAL_UNIT: f64 = 149_597_870.7;
+/// Секунд в сутках
+const SECONDS_PER_DAY: f64 = 86_400.;
+/// Допустимое отличие даты строки от выбранной эпохи (в сутках)
+const EPOCH_TOLERANCE: f64 = 1e-6;
+
+/// Векторы состояния объектов относительно Солнца
+/// на выбранную эпоху, полученные из таблиц JPL Horizons
+pub struct HorizonsStates {
+    /// Векторы состояния по названию объекта в нижнем регистре
+    states: HashMap<Box<str>, StateVectors>,
+}
+
+impl HorizonsStates {
+    /// Чтение всех таблиц векторов из папки
+    pub fn read_directory(
+        path: &str,
+        julian_date: f64,
+    ) -> Result<Self, HorizonsError> {
+        let mut paths = read_dir(path)
+            .map_err(|error| {
+                HorizonsError::in_file(path, error)
+            })?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| {
+                HorizonsError::in_file(path, error)
+            })?;
+
+        paths.retain(|path| path.is_file());
+        paths.sort();
+
+        if paths.is_empty() {
+            return Err(HorizonsError::in_file(
+                path,
+                HorizonsError::NoTables,
+            ));
+        }
+
+        // Строки таблиц на выбранную эпоху по идентификатору объекта
+        let mut tables = HashMap::new();
+
+        for path in paths {
+            let table = read_to_string(&path)
+                .map_err(HorizonsError::from)
+                .and_then(|text| VectorTable::parse(&text))
+                .and_then(|table| table.at(julian_date))
+                .map_err(|error| {
+                    HorizonsError::in_file(&path, error)
+                })?;
+
+            if let Some(previous) =
+                tables.insert(table.target.id, table)
+            {
+                return Err(
+                    HorizonsError::DuplicateTarget(
+                        previous.target.name,
+                    ),
+                );
+            }
+        }
+
+        let states = tables
+            .values()
+            .map(|table| {
+                heliocentric_state(&tables, table.target.id)
+                    .map(|(position, velocity)| {
+                        (
+                            table
+                                .target
+                                .name
+                                .to_lowercase()
+                                .into(),
+                            StateVectors::new(
+                                position, velocity,
+                            ),
+                        )
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { states })
+    }
+
+    /// Вектор состояния объекта по его названию
+    pub fn get(&self, name: &str) -> Option<&StateVectors> {
+        self.states.get(name.to_lowercase().as_str())
+    }
+}
+
+/// Состояние объекта относительно Солнца (позиция и скорость)
+fn heliocentric_state(
+    tables: &HashMap<i64, EpochRow>,
+    id: i64,
+) -> Result<(Vector<f64>, Vector<f64>), HorizonsError> {
+    // Цепочка центров не может быть длиннее числа таблиц
+    let mut position = Vector::new(0., 0.);
+    let mut velocity = Vector::new(0., 0.);
+    let mut current = id;
+
+    for _ in 0..=tables.len() {
+        if current == SUN_ID {
+            return Ok((position, velocity));
+        }
+
+        match tables.get(&current) {
+            Some(row) => {
+                position += row.position;
+                velocity += row.velocity;
+                current = row.center.id;
+            }
+            // Барицентр вычисляется через положение Солнца относительно него
+            None if current
+                == SOLAR_SYSTEM_BARYCENTER_ID =>
+            {
+                let sun = tables
+                    .get(&SUN_ID)
+                    .filter(|sun| {
+                        sun.center.id
+                            == SOLAR_SYSTEM_BARYCENTER_ID
+                    })
+                    .ok_or_else(|| {
+                        unresolved_center(tables, id)
+                    })?;
+
+                return Ok((
+                    position - sun.position,
+                    velocity - sun.velocity,
+                ));
+            }
+            None => {
+                return Err(unresolved_center(tables, id));
+            }
+        }
+    }
+
+    Err(unresolved_center(tables, id))
+}
+
+/// Ошибка невозможности привести объект к Солнцу
+fn unresolved_center(
+    tables: &HashMap<i64, EpochRow>,
+    id: i64,
+) -> HorizonsError {
+    let row = &tables[&id];
+
+    HorizonsError::UnresolvedCenter {
+        target: row.target.name.clone(),
+        center: row.center.name.clone(),
+    }
+}
+
+/// Объект, упомянутый в заголовке таблицы
+#[derive(Clone)]
+struct HorizonsBody {
+    /// Название
+    name: String,
+    /// Идентификатор
+    id: i64,
+}
+
+impl HorizonsBody {
+    /// Разбор значения вида `Earth (399)`
+    fn parse(value: &str) -> Option<Self> {
+        let value = value
+            .split('{')
+            .next()
+            .unwrap_or_default()
+            .trim();
+
+        let (name, id) = value.rsplit_once('(')?;
+        let id =
+            id.strip_suffix(')')?.trim().parse().ok()?;
+
+        Some(Self { name: name.trim().to_owned(), id })
+    }
+}
+
+/// Строка таблицы векторов
+struct VectorRow {
+    /// Юлианская дата
+    julian_date: f64,
+    /// Позиция в км
+    position: Vector<f64>,
+    /// Скорость в км/с
+    velocity: Vector<f64>,
+}
+
+/// Строка таблицы на выбранную эпоху
+struct EpochRow {
+    /// Объект
+    target: HorizonsBody,
+    /// Центр, относительно которого заданы векторы
+    center: HorizonsBody,
+    /// Позиция в км
+    position: Vector<f64>,
+    /// Скорость в км/с
+    velocity: Vector<f64>,
+}
+
+/// Таблица векторов (результат запроса `VECTORS`),
+/// координаты берутся в плоскости эклиптики
+struct VectorTable {
+    /// Объект
+    target: HorizonsBody,
+    /// Центр, относительно которого заданы векторы
+    center: HorizonsBody,
+    /// Строки таблицы
+    rows: Vec<VectorRow>,
+}
+
+impl VectorTable {
+    /// Разбор текста таблицы
+    fn parse(text: &str) -> Result<Self, HorizonsError> {
+        let target = header_value(text, "Target body name")
+            .ok_or(HorizonsError::MissingHeader(
+                "Target body name",
+            ))?;
+        let target = HorizonsBody::parse(target)
+            .ok_or_else(|| {
+                HorizonsError::MalformedHeader(
+                    target.into(),
+                )
+            })?;
+
+        let center = header_value(text, "Center body name")
+            .ok_or(HorizonsError::MissingHeader(
+                "Center body name",
+            ))?;
+        let center = HorizonsBody::parse(center)
+            .ok_or_else(|| {
+                HorizonsError::MalformedHeader(
+                    center.into(),
+                )
+            })?;
+
+        // Множители перевода в км и км/с
+        let units = header_value(text, "Output units")
+            .map(|units| {
+                units
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or_default()
+            })
+            .unwrap_or("KM-S");
+        let (position_factor, velocity_factor) = match units
+        {
+            "KM-S" => (1., 1.),
+            "KM-D" => (1., 1. / SECONDS_PER_DAY),
+            "AU-D" => (
+                ASTRONOMICAL_UNIT,
+                ASTRONOMICAL_UNIT / SECONDS_PER_DAY,
+            ),
+            units => {
+                return Err(
+                    HorizonsError::UnsupportedUnits(
+                        units.into(),
+                    ),
+                );
+            }
+        };
+
+        let lines = text.lines().enumerate();
+
+        // Строки между маркерами начала и конца данных
+        let start = lines
+            .clone()
+            .find(|(_, line)| line.trim() == "$$SOE")
+            .map(|(index, _)| index)
+            .ok_or(HorizonsError::MissingTable)?;
+        let end = lines
+            .clone()
+            .skip(start)
+            .find(|(_, line)| line.trim() == "$$EOE")
+            .map(|(index, _)| index)
+            .ok_or(HorizonsError::MissingTable)?;
+
+        let data = lines
+            .skip(start + 1)
+            .take(end - start - 1)
+            .filter(|(_, line)| !line.trim().is_empty())
+            .collect::<Vec<_>>();
+
+        let rows = if data
+            .first()
+            .is_some_and(|(_, line)| line.contains(','))
+        {
+            Self::parse_csv_rows(text, &data)?
+        } else {
+            Self::parse_text_rows(&data)?
+        };
+
+        let rows = rows
+            .into_iter()
+            .map(|row| VectorRow {
+                position: row.position * position_factor,
+                velocity: row.velocity * velocity_factor,
+                ..row
+            })
+            .collect();
+
+        Ok(Self { target, center, rows })
+    }
+
+    /// Разбор строк обычного формата:
+    /// строка с датой, затем строки вида `X = ... Y = ...`
+    fn parse_text_rows(
+        data: &[(usize, &str)],
+    ) -> Result<Vec<VectorRow>, HorizonsError> {
+        let mut rows = Vec::new();
+        let mut current: Option<(
+            usize,
+            f64,
+            HashMap<&str, f64>,
+        )> = None;
+
+        for &(index, line) in data {
+            let tokens = key_value_tokens(line);
+
+            if tokens.is_empty() {
+                return Err(HorizonsError::malformed_row(
+                    index, line,
+                ));
+            }
+
+            // Новая запись начинается со строки с юлианской датой
+            if let Some(julian_date) =
+                tokens.first().and_then(|(key, _)| {
+                    key.parse::<f64>().ok()
+                })
+            {
+                if let Some(record) = current.take() {
+                    rows.push(Self::text_row(record)?);
+                }
+                current = Some((
+                    index,
+                    julian_date,
+                    HashMap::new(),
+                ));
+                continue;
+            }
+
+            let (_, _, values) =
+                current.as_mut().ok_or_else(|| {
+                    HorizonsError::malformed_row(
+                        index, line,
+                    )
+                })?;
+
+            for (key, value) in tokens {
+                let value =
+                    value.parse().map_err(|_| {
+                        HorizonsError::malformed_row(
+                            index, line,
+                        )
+                    })?;
+                values.insert(key, value);
+            }
+        }
+
+        if let Some(record) = current {
+            rows.push(Self::text_row(record)?);
+        }
+
+        Ok(rows)
+    }
+
+    /// Сборка строки таблицы из значений обычного формата
+    fn text_row(
+        (index, julian_date, values): (
+            usize,
+            f64,
+            HashMap<&str, f64>,
+        ),
+    ) -> Result<VectorRow, HorizonsError> {
+        let value = |key: &'static str| {
+            values.get(key).copied().ok_or(
+                HorizonsError::MissingValue {
+                    line: index + 1,
+                    key,
+                },
+            )
+        };
+
+        Ok(VectorRow {
+            julian_date,
+            position: Vector::new(value("X")?, value("Y")?),
+            velocity: Vector::new(
+                value("VX")?,
+                value("VY")?,
+            ),
+        })
+    }
+
+    /// Разбор строк формата CSV по названиям столбцов
+    fn parse_csv_rows(
+        text: &str,
+        data: &[(usize, &str)],
+    ) -> Result<Vec<VectorRow>, HorizonsError> {
+        // Строка с названиями столбцов
+        let columns = text
+            .lines()
+            .map(|line| {
+                line.split(',')
+                    .map(str::trim)
+                    .collect::<Vec<_>>()
+            })
+            .find(|columns| columns.contains(&"JDTDB"))
+            .ok_or(HorizonsError::MissingHeader("JDTDB"))?;
+
+        let column = |name: &'static str| {
+            columns
+                .iter()
+                .position(|column| *column == name)
+                .ok_or(HorizonsError::MissingHeader(name))
+        };
+
+        let indices = [
+            column("JDTDB")?,
+            column("X")?,
+            column("Y")?,
+            column("VX")?,
+            column("VY")?,
+        ];
+
+        data.iter()
+            .map(|&(index, line)| {
+                let fields = line
+                    .split(',')
+                    .map(str::trim)
+                    .collect::<Vec<_>>();
+
+                let values = indices
+                    .iter()
+                    .map(|&column| {
+                        fields.get(column).and_then(
+                            |field| field.parse().ok(),
+                        )
+                    })
+                    .collect::<Option<Vec<f64>>>()
+                    .ok_or_else(|| {
+                        HorizonsError::malformed_row(
+                            index, line,
+                        )
+                    })?;
+
+                Ok(VectorRow {
+                    julian_date: values[0],
+                    position: Vector::new(
+                        values[1], values[2],
+                    ),
+                    velocity: Vector::new(
+                        values[3], values[4],
+                    ),
+                })
+            })
+            .collect()
+    }
+}
+
+impl VectorTable {
+    /// Строка таблицы на выбранную эпоху
+    fn at(
+        self,
+        julian_date: f64,
+    ) -> Result<EpochRow, HorizonsError> {
+        let row = self
+            .rows
+            .into_iter()
+            .find(|row| {
+                (row.julian_date - julian_date).abs()
+                    < EPOCH_TOLERANCE
+            })
+            .ok_or(HorizonsError::EpochNotFound(
+                julian_date,
+            ))?;
+
+        Ok(EpochRow {
+            target: self.target,
+            center: self.center,
+            position: row.position,
+            velocity: row.velocity,
+        })
+    }
+}
+
+/// Значение строки заголовка вида `Название : значение`
+fn header_value<'a>(
+    text: &'a str,
+    name: &str,
+) -> Option<&'a str> {
+    text.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        (key.trim() == name).then_some(value.trim())
+    })
+}
+
+/// Пары `ключ = значение` строки
+fn key_value_tokens(line: &str) -> Vec<(&str, &str)> {
+    let mut tokens = Vec::new();
+    let mut rest = line;
+
+    while let Some((key_part, value_part)) =
+        rest.split_once('=')
+    {
+        let key = key_part
+            .split_whitespace()
+            .last()
+            .unwrap_or_default();
+        let value_part = value_part.trim_start();
+        let value_end = value_part
+            .find(char::is_whitespace)
+            .unwrap_or(value_part.len());
+
+        tokens.push((key, &value_part[..value_end]));
+        rest = &value_part[value_end..];
+    }
+
+    tokens
+}
+
+/// Ошибка импорта таблиц векторов JPL Horizons
+#[derive(Debug)]
+pub enum HorizonsError {
+    /// Ошибка чтения
+    Io(io::Error),
+    /// В папке нет таблиц
+    NoTables,
+    /// Нет строки заголовка
+    MissingHeader(&'static str),
+    /// Некорректная строка заголовка
+    MalformedHeader(String),
+    /// Неподдерживаемые единицы измерения
+    UnsupportedUnits(String),
+    /// Нет маркеров `$$SOE`/`$$EOE`
+    MissingTable,
+    /// Некорректная строка данных
+    MalformedRow { line: usize, text: String },
+    /// В записи нет значения
+    MissingValue { line: usize, key: &'static str },
+    /// Нет строки на выбранную эпоху
+    EpochNotFound(f64),
+    /// Несколько таблиц для одного объекта
+    DuplicateTarget(String),
+    /// Центр таблицы нельзя привести к Солнцу
+    UnresolvedCenter { target: String, center: String },
+    /// Ошибка в конкретном файле
+    InFile { path: PathBuf, error: Box<HorizonsError> },
+}
+
+impl HorizonsError {
+    fn in_file(
+        path: impl AsRef<Path>,
+        error: impl Into<HorizonsError>,
+    ) -> Self {
+        Self::InFile {
+            path: path.as_ref().to_path_buf(),
+            error: Box::new(error.into()),
+        }
+    }
+
+    fn malformed_row(index: usize, line: &str) -> Self {
+        Self::MalformedRow {
+            line: index + 1,
+            text: line.trim().to_owned(),
+        }
+    }
+}
+
+impl From<io::Error> for HorizonsError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl Display for HorizonsError {
+    fn fmt(
+        &self,
+        fmt: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(fmt, "{error}"),
+            Self::NoTables => {
+                write!(fmt, "no vector tables found")
+            }
+            Self::MissingHeader(name) => {
+                write!(fmt, "missing \"{name}\" header")
+            }
+            Self::MalformedHeader(value) => write!(
+                fmt,
+                "malformed body name \"{value}\", expected \"Name (id)\""
+            ),
+            Self::UnsupportedUnits(units) => write!(
+                fmt,
+                "unsupported output units {units}, expected KM-S, KM-D or AU-D"
+            ),
+            Self::MissingTable => write!(
+                fmt,
+                "no data between $$SOE and $$EOE markers"
+            ),
+            Self::MalformedRow { line, text } => write!(
+                fmt,
+                "line {line}: malformed row \"{text}\""
+            ),
+            Self::MissingValue { line, key } => write!(
+                fmt,
+                "line {line}: record has no {key} value"
+            ),
+            Self::EpochNotFound(julian_date) => write!(
+                fmt,
+                "no row for epoch JD {julian_date}"
+            ),
+            Self::DuplicateTarget(name) => {
+                write!(fmt, "several tables for {name}")
+            }
+            Self::UnresolvedCenter { target, center } => {
+                write!(
+                    fmt,
+                    "state of {target} is relative to {center}, which is neither the Sun nor an imported body"
+                )
+            }
+            Self::InFile { path, error } => {
+                write!(fmt, "{}: {error}", path.display())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Таблица обычного формата: Земля относительно Солнца
+    const TEXT_TABLE: &str = "\
+*******************************************************************************
+Target body name: Earth (399)                     {source: DE441}
+Center body name: Sun (10)                        {source: DE441}
+Output units    : KM-S
+*******************************************************************************
+$$SOE
+2451545.000000000 = A.D. 2000-Jan-01 12:00:00.0000 TDB
+ X =-2.649903422886233E+07 Y = 1.446972967365423E+08 Z =-6.111494259072561E+02
+ VX=-2.979426006719171E+01 VY=-5.018052308799792E+00 VZ= 1.842435050242003E-04
+ LT= 4.908525052481006E+02 RG= 1.471028359337675E+08 RR=-1.691203011360640E-01
+2451546.000000000 = A.D. 2000-Jan-02 12:00:00.0000 TDB
+ X =-2.907028467418828E+07 Y = 1.442354307402406E+08 Z =-6.002637412399054E+02
+ VX=-2.970538297404180E+01 VY=-5.670418093706838E+00 VZ= 1.668547064003108E-04
+$$EOE
+*******************************************************************************
+";
+
+    /// Таблица формата CSV в а.е. и а.е./сут: Луна относительно Земли
+    const CSV_TABLE: &str = "\
+*******************************************************************************
+Target body name: Moon (301)                      {source: DE441}
+Center body name: Earth (399)                     {source: DE441}
+Output units    : AU-D
+*******************************************************************************
+            JDTDB,            Calendar Date (TDB),                      X,                      Y,                      Z,                     VX,                     VY,                     VZ,
+**************************************************************************************************************************************************************************************************
+$$SOE
+2451545.000000000, A.D. 2000-Jan-01 12:00:00.0000, -1.949281649386014E-03, -1.838126090603010E-03,  2.424579265877520E-04,  3.716704231125430E-04, -4.138065224148550E-04, -6.053426549806500E-06,
+$$EOE
+*******************************************************************************
+";
+
+    #[test]
+    fn parses_text_table() {
+        let table = VectorTable::parse(TEXT_TABLE).unwrap();
+
+        assert_eq!(table.target.name, "Earth");
+        assert_eq!(table.target.id, 399);
+        assert_eq!(table.center.id, SUN_ID);
+        assert_eq!(table.rows.len(), 2);
+
+        let row = table.at(2451546.).unwrap();
+        assert_eq!(row.position.x, -2.907028467418828e7);
+        assert_eq!(row.position.y, 1.442354307402406e8);
+        assert_eq!(row.velocity.x, -2.97053829740418e1);
+        assert_eq!(row.velocity.y, -5.670418093706838);
+    }
+
+    #[test]
+    fn parses_csv_table_in_astronomical_units() {
+        let table = VectorTable::parse(CSV_TABLE).unwrap();
+
+        assert_eq!(table.target.name, "Moon");
+        assert_eq!(table.center.id, 399);
+
+        let row = table.at(2451545.).unwrap();
+        assert!(
+            (row.position.x
+                - -1.949281649386014e-3
+                    * ASTRONOMICAL_UNIT)
+                .abs()
+                < 1e-6
+        );
+        assert!(
+            (row.velocity.y
+                - -4.13806522414855e-4 * ASTRONOMICAL_UNIT
+                    / SECONDS_PER_DAY)
+                .abs()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn resolves_state_relative_to_sun_through_centers() {
+        let tables = [TEXT_TABLE, CSV_TABLE]
+            .into_iter()
+            .map(|text| {
+                let row = VectorTable::parse(text)
+                    .unwrap()
+                    .at(2451545.)
+                    .unwrap();
+                (row.target.id, row)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let (position, velocity) =
+            heliocentric_state(&tables, 301).unwrap();
+        let earth = &tables[&399];
+        let moon = &tables[&301];
+
+        assert_eq!(
+            position,
+            earth.position + moon.position
+        );
+        assert_eq!(
+            velocity,
+            earth.velocity + moon.velocity
+        );
+    }
+
+    #[test]
+    fn rejects_table_without_end_marker() {
+        let text = TEXT_TABLE.replace("$$EOE", "");
+
+        assert!(matches!(
+            VectorTable::parse(&text),
+            Err(HorizonsError::MissingTable)
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_csv_row() {
+        let text = CSV_TABLE.replace(
+            "-1.838126090603010E-03",
+            "not a number",
+        );
+
+        assert!(matches!(
+            VectorTable::parse(&text),
+            Err(HorizonsError::MalformedRow {
+                line: 9,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_text_record_without_velocity() {
+        let text = TEXT_TABLE.replace(
+            " VX=-2.979426006719171E+01 VY=-5.018052308799792E+00 VZ= 1.842435050242003E-04\n",
+            "",
+        );
+
+        assert!(matches!(
+            VectorTable::parse(&text),
+            Err(HorizonsError::MissingValue {
+                key: "VX",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_missing_header_and_unsupported_units() {
+        let text = TEXT_TABLE.replace(
+            "Center body name: Sun (10)",
+            "Center: Sun (10)",
+        );
+        assert!(matches!(
+            VectorTable::parse(&text),
+            Err(HorizonsError::MissingHeader(
+                "Center body name"
+            ))
+        ));
+
+        let text = TEXT_TABLE.replace("KM-S", "AU-S");
+        assert!(matches!(
+            VectorTable::parse(&text),
+            Err(HorizonsError::UnsupportedUnits(_))
+        ));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod comet_elements;
+pub mod config_data;
+pub mod horizons_vectors;
+pub mod snapshot;
+pub mod solar_system_data;
+pub mod update;
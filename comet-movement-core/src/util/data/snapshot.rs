@@ -0,0 +1,155 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::{
+    color::Color,
+    geometry::{point::Point, vector::Vector},
+    objects::{
+        Object, ObjectMotion,
+        id::{ObjectId, ObjectIdGenerator},
+        movement::trajectory::Trajectory,
+    },
+    physics::orbit::StateVectors,
+};
+
+/// Снимок космических объектов
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SpaceSnapshot {
+    /// Генератор идентификаторов объектов
+    pub object_ids: ObjectIdGenerator,
+    /// Двигается ли Солнце
+    pub is_sun_moving: bool,
+    /// Солнце
+    pub sun: BodySnapshot,
+    /// Планеты со спутниками
+    pub planets: Vec<PlanetSnapshot>,
+    /// Кометы
+    pub comets: CometsSnapshot,
+}
+
+/// Снимок тела
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BodySnapshot {
+    /// Идентификатор
+    pub id: ObjectId,
+    /// Название
+    pub name: Box<str>,
+    /// Масса в кг
+    pub mass: f64,
+    /// Радиус в км
+    pub radius: f64,
+    /// Путь к изображению
+    pub image_path: Box<str>,
+    /// Движение
+    pub movement: MovementSnapshot,
+}
+
+impl BodySnapshot {
+    /// Снимок двигающегося объекта
+    pub fn of<T: Object + ObjectMotion + ?Sized>(
+        object: &T,
+        with_trajectory: bool,
+    ) -> Self {
+        Self {
+            id: object.id(),
+            name: object.name().into(),
+            mass: object.mass().value(),
+            radius: object.radius().value(),
+            image_path: object.image_path().into(),
+            movement: object
+                .movement()
+                .snapshot(with_trajectory),
+        }
+    }
+}
+
+/// Снимок планеты
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PlanetSnapshot {
+    /// Планета
+    #[serde(flatten)]
+    pub body: BodySnapshot,
+    /// Спутники
+    pub satellites: Vec<BodySnapshot>,
+}
+
+/// Снимок движения
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MovementSnapshot {
+    /// Позиция в км
+    pub position: Point<f64>,
+    /// Скорость в км/с
+    pub velocity: Vector<f64>,
+    /// Цвет траектории
+    pub trajectory_color: Color,
+    /// Траектория, без неё она начинается заново с позиции
+    pub trajectory: Option<Trajectory>,
+}
+
+impl MovementSnapshot {
+    /// Вектор состояния снимка
+    pub fn state(&self) -> StateVectors {
+        StateVectors::new(
+            Vector::new(self.position.x, self.position.y),
+            self.velocity,
+        )
+    }
+}
+
+/// Снимок состояния комет
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CometsSnapshot {
+    /// Новый номер кометы
+    pub new_comet_number: u32,
+    /// Являются ли кометы пробными частицами
+    pub are_test_particles: bool,
+    /// Кометы
+    pub comets: Vec<BodySnapshot>,
+}
+
+/// Ошибка восстановления из снимка
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// Тела снимка не совпадают с данными Солнечной системы
+    MismatchedBodies,
+    /// Комет в снимке больше, чем может быть
+    TooManyComets { count: usize, maximum: u32 },
+    /// Изображения кометы нет среди изображений комет
+    UnknownCometImage {
+        name: Box<str>,
+        image_path: Box<str>,
+    },
+}
+
+impl Display for SnapshotError {
+    fn fmt(
+        &self,
+        fmt: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Self::MismatchedBodies => write!(
+                fmt,
+                "тела снимка не совпадают \
+                 с данными Солнечной системы"
+            ),
+            Self::TooManyComets { count, maximum } => {
+                write!(
+                    fmt,
+                    "в снимке {count} комет, \
+                     допускается не больше {maximum}"
+                )
+            }
+            Self::UnknownCometImage {
+                name,
+                image_path,
+            } => {
+                write!(
+                    fmt,
+                    "у кометы {name} неизвестное \
+                     изображение {image_path}"
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use serde::Deserialize;
+
+/// Данные для всех обектом Солнечной системы
+#[derive(Deserialize)]
+pub struct Data {
+    /// Эпоха орбитальных элементов (юлианская дата)
+    pub epoch: f64,
+    pub sun: SunData,
+    pub planets: Box<[PlanetData]>,
+    pub comet: CometData,
+}
+
+/// Данные Солнца
+#[derive(Deserialize)]
+pub struct SunData {
+    pub consts: SunConsts,
+    /// Название изображения Солнца
+    pub image_filename: Box<str>,
+}
+
+/// Данные констант Солнца
+#[derive(Deserialize)]
+pub struct SunConsts {
+    /// Масса
+    pub mass: f64,
+    /// Радиус
+    pub radius: f64,
+}
+
+/// Данные планеты
+#[derive(Deserialize)]
+pub struct PlanetData {
+    /// Название
+    pub name: Box<str>,
+    pub consts: ObjectConsts,
+    /// Элементы орбиты вокруг Солнца
+    #[serde(default)]
+    pub orbital_elements: Option<OrbitalElementsData>,
+    /// Название изображения планеты
+    pub image_filename: Box<str>,
+    /// Спутники планеты
+    pub satellites: Box<[SatelliteData]>,
+}
+
+/// Данные спутника
+#[derive(Deserialize)]
+pub struct SatelliteData {
+    /// Название
+    pub name: Box<str>,
+    pub consts: ObjectConsts,
+    /// Элементы орбиты вокруг планеты
+    #[serde(default)]
+    pub orbital_elements: Option<OrbitalElementsData>,
+    /// Название изображения спутника
+    pub image_filename: Box<str>,
+}
+
+/// Данные констант объекта (планеты или спутника)
+#[derive(Deserialize)]
+pub struct ObjectConsts {
+    /// Масса
+    pub mass: f64,
+    /// Радиус круговой орбиты, если элементы орбиты не заданы
+    pub orbit: f64,
+    /// Радиус
+    pub radius: f64,
+}
+
+/// Данные кеплеровых элементов орбиты
+#[derive(Deserialize)]
+pub struct OrbitalElementsData {
+    /// Большая полуось
+    pub semi_major_axis: f64,
+    /// Эксцентриситет
+    pub eccentricity: f64,
+    /// Долгота перицентра (в градусах)
+    pub argument_of_periapsis: f64,
+    /// Средняя аномалия на эпоху (в градусах)
+    pub mean_anomaly: f64,
+}
+
+/// Данные возможных значений кометы
+#[derive(Deserialize)]
+pub struct CometData {
+    /// Возможные скорости (минимальная и максимальная)
+    pub possible_velocities: [f64; 2],
+    /// Возможные массы (минимальная и максимальная)
+    pub possible_masses: [f64; 2],
+    /// Возможные радиусы (минимальный и максимальный)
+    pub possible_radii: [f64; 2],
+    /// Название изображений комет
+    pub images_filenames: Box<[Box<str>]>,
+    /// Профили появления комет
+    #[serde(default)]
+    pub spawn_profiles: Box<[SpawnProfileData]>,
+}
+
+/// Данные профиля появления комет: диапазоны (минимум, максимум)
+/// элементов орбиты, из которых выбираются значения
+#[derive(Deserialize)]
+pub struct SpawnProfileData {
+    /// Название
+    pub name: Box<str>,
+    /// Расстояние в перигелии (в а. е.)
+    pub perihelion_distance: [f64; 2],
+    /// Эксцентриситет
+    pub eccentricity: [f64; 2],
+    /// Аргумент перигелия (в градусах)
+    pub argument_of_perihelion: [f64; 2],
+    /// Долгота восходящего узла (в градусах)
+    #[serde(default)]
+    pub longitude_of_ascending_node: [f64; 2],
+    /// Наклонение (в градусах)
+    #[serde(default)]
+    pub inclination: [f64; 2],
+    /// Время до прохождения перигелия (в сутках),
+    /// отрицательное — после прохождения
+    pub time_to_perihelion: [f64; 2],
+}
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use crate::util::{
+    data::snapshot::BodySnapshot,
+    geometry::{point::Point, vector::Vector},
+    objects::{
+        id::{ObjectId, ObjectIdGenerator},
+        movement::trajectory::TrajectoryUpdate,
+    },
+};
+
+/// Количество позиций траектории, уже переданных копии,
+/// по идентификатору объекта
+pub type PublishedPositions = HashMap<ObjectId, u64>;
+
+/// Изменение космических объектов с прошлой публикации
+pub struct SpaceUpdate {
+    /// Генератор идентификаторов объектов
+    pub object_ids: ObjectIdGenerator,
+    /// Солнце
+    pub sun: MovementUpdate,
+    /// Планеты со спутниками
+    pub planets: Vec<PlanetUpdate>,
+    /// Кометы
+    pub comets: CometsUpdate,
+}
+
+/// Изменение планеты
+pub struct PlanetUpdate {
+    /// Планета
+    pub body: MovementUpdate,
+    /// Спутники
+    pub satellites: Vec<MovementUpdate>,
+}
+
+/// Изменение комет
+pub struct CometsUpdate {
+    /// Новый номер кометы
+    pub new_comet_number: u32,
+    /// Все кометы по порядку, отсутствующие удалены
+    pub comets: Vec<CometUpdate>,
+}
+
+/// Изменение кометы
+pub enum CometUpdate {
+    /// Комета уже есть в копии
+    Existing { id: ObjectId, movement: MovementUpdate },
+    /// Новая комета
+    New(BodySnapshot),
+}
+
+/// Изменение движения
+pub struct MovementUpdate {
+    /// Позиция в км
+    pub position: Point<f64>,
+    /// Скорость в км/с
+    pub velocity: Vector<f64>,
+    /// Новые позиции траектории
+    pub trajectory: TrajectoryUpdate,
+}
@@ -1,16 +1,16 @@
-use iced::Point;
+use crate::util::geometry::point::Point;
 
 /// Структура круга (окружности)
 pub struct Circle {
     /// Позиция центра
-    center: Point,
+    center: Point<f64>,
     /// Радиус
-    radius: f32,
+    radius: f64,
 }
 
 impl Circle {
     #[inline(always)]
-    pub fn new(center: Point, radius: f32) -> Self {
+    pub fn new(center: Point<f64>, radius: f64) -> Self {
         Self { center, radius }
     }
 }
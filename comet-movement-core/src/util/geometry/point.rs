@@ -0,0 +1,59 @@
+use num_traits::Num;
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Sub};
+
+use crate::util::geometry::vector::Vector;
+
+/// Точка на плоскости
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Default,
+    Serialize,
+    Deserialize,
+)]
+pub struct Point<T = f64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    #[inline(always)]
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vector<T>> for Point<T> {
+    type Output = Self;
+
+    fn add(self, vector: Vector<T>) -> Self::Output {
+        Self::new(self.x + vector.x, self.y + vector.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vector<T>> for Point<T> {
+    type Output = Self;
+
+    fn sub(self, vector: Vector<T>) -> Self::Output {
+        Self::new(self.x - vector.x, self.y - vector.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// Масштабирование точки
+pub fn scale_point<T: Num + Copy>(
+    Point { x, y }: Point<T>,
+    scale: T,
+) -> Point<T> {
+    Point::new(x / scale, y / scale)
+}
@@ -0,0 +1,98 @@
+use std::ops::{
+    Add, AddAssign, Div, Mul, Neg, Sub, SubAssign,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Вектор на плоскости
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Default,
+    Serialize,
+    Deserialize,
+)]
+pub struct Vector<T = f64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vector<T> {
+    #[inline(always)]
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vector<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vector<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Add<Output = T> + Copy> AddAssign for Vector<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> SubAssign for Vector<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vector<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Self::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T: Div<Output = T> + Copy> Div<T> for Vector<T> {
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self::Output {
+        Self::new(self.x / scalar, self.y / scalar)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vector<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// Структура вектора через единичный вектор
+#[derive(Clone)]
+pub struct VectorValue<T, V> {
+    /// Значение вектора
+    pub value: T,
+    /// Единичный вектор
+    pub unit_vector: Vector<V>,
+}
+
+impl<T, V> VectorValue<T, V> {
+    #[inline(always)]
+    pub const fn new(
+        value: T,
+        unit_vector: Vector<V>,
+    ) -> Self {
+        Self { value, unit_vector }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod color;
+pub mod data;
+pub mod geometry;
+pub mod objects;
+pub mod physics;
+pub mod time;
@@ -11,9 +11,6 @@ pub struct SolarSystemObjectConsts {
     /// Масса
     #[getset(get_copy, vis = "pub")]
     mass: Quantity<Kilograms>,
-    /// Начальная орбита
-    #[getset(get_copy, vis = "pub")]
-    initial_orbit: Quantity<Kilometers>,
     /// Радиус
     #[getset(get_copy, vis = "pub")]
     radius: Quantity<Kilometers>,
@@ -21,16 +18,9 @@ pub struct SolarSystemObjectConsts {
 
 impl SolarSystemObjectConsts {
     #[inline(always)]
-    pub fn new(
-        mass: f64,
-        initial_orbit: f32,
-        radius: f32,
-    ) -> Self {
+    pub fn new(mass: f64, radius: f64) -> Self {
         Self {
             mass: Quantity::new(Kilograms::new(mass)),
-            initial_orbit: Quantity::new(Kilometers::new(
-                initial_orbit,
-            )),
             radius: Quantity::new(Kilometers::new(radius)),
         }
     }
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// Постоянный идентификатор объекта, назначаемый при создании
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub struct ObjectId(u32);
+
+/// Генератор идентификаторов объектов
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ObjectIdGenerator {
+    /// Следующий свободный идентификатор
+    next_id: u32,
+}
+
+impl ObjectIdGenerator {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self { next_id: 0 }
+    }
+}
+
+impl Default for ObjectIdGenerator {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ObjectIdGenerator {
+    /// Получение нового идентификатора
+    pub fn generate(&mut self) -> ObjectId {
+        let id = ObjectId(self.next_id);
+        self.next_id += 1;
+        id
+    }
+}
@@ -1,18 +1,16 @@
-use iced::{Color, Point, widget::image};
-use num_traits::ToPrimitive;
-
 use crate::util::{
-    geometry::point::scale_point,
+    color::Color,
+    geometry::point::{Point, scale_point},
     objects::{
+        id::ObjectId,
         movement::ObjectMovement,
         values::{FormValues, GravitationalForceValues},
     },
     physics::{
         quantities::{
-            Quantity,
+            Quantity, point_without_quantity_units,
             quantity_units::{
                 Kilograms, Kilometers, KilometersPerSecond,
-                Seconds,
             },
         },
         vector::VectorValue,
@@ -20,6 +18,7 @@ use crate::util::{
 };
 
 pub mod consts;
+pub mod id;
 pub mod movement;
 pub mod values;
 
@@ -27,6 +26,8 @@ pub mod values;
 pub trait Object:
     GravitationalForceValues + FormValues + ObjectScalingSizes
 {
+    /// Идентификатор
+    fn id(&self) -> ObjectId;
     /// Имя
     fn name(&self) -> &str;
     /// Масса
@@ -35,8 +36,13 @@ pub trait Object:
     fn radius(&self) -> Quantity<Kilometers>;
     /// Позиция
     fn position(&self) -> Point<Quantity<Kilometers>>;
-    /// Изображение
-    fn image(&self) -> &image::Handle;
+    /// Путь к изображению
+    fn image_path(&self) -> &str;
+    /// Пробная ли частица: её притягивают, но сама она не притягивает
+    #[inline(always)]
+    fn is_test_particle(&self) -> bool {
+        false
+    }
 }
 
 /// Типаж масштабирования значений объекта
@@ -44,24 +50,23 @@ pub trait ObjectScalingSizes {
     /// Масштабирование радиуса
     fn scaled_radius(&self, scale: u32) -> f32;
     /// Масштабирование позиции
-    fn scaled_position(&self, scale: u32) -> Point;
+    fn scaled_position(&self, scale: u32) -> Point<f32>;
 }
 
 impl<T: Object> ObjectScalingSizes for T {
     fn scaled_radius(&self, scale: u32) -> f32 {
-        self.radius().value().to_f32().unwrap()
-            / (scale as f32)
+        (self.radius().value() / scale as f64) as f32
     }
 
-    fn scaled_position(&self, scale: u32) -> Point {
-        let Point { x, y } = self.position();
-        scale_point(
-            Point::new(
-                x.value().to_f32().unwrap(),
-                y.value().to_f32().unwrap(),
-            ),
-            scale as f32,
-        )
+    /// Масштабирование выполняется в `f64`,
+    /// в `f32` переводится только результат для холста
+    fn scaled_position(&self, scale: u32) -> Point<f32> {
+        let Point { x, y } = scale_point(
+            point_without_quantity_units(self.position()),
+            scale as f64,
+        );
+
+        Point::new(x as f32, y as f32)
     }
 }
 
@@ -73,18 +78,18 @@ pub trait ObjectMotion {
 
 /// Типаж двигающихся объектов вокруг Солнца
 pub trait MovingObject: Object + ObjectMotion {
-    /// Обновление позиции после движения в течение некоторого промежутка времени
+    /// Обновление позиции и скорости после движения
     fn update_position(
         &mut self,
-        velocity_change: VectorValue<KilometersPerSecond>,
-        time_interval: Quantity<Seconds>,
+        position: Point<Quantity<Kilometers>>,
+        velocity: VectorValue<KilometersPerSecond>,
     );
     /// Траектория движения
     fn trajectory<'a>(
         &'a self,
         step: u32,
         scale: f32,
-    ) -> Box<dyn Iterator<Item = Point> + 'a>;
+    ) -> Box<dyn Iterator<Item = Point<f32>> + 'a>;
     /// Цвет траектории
     fn trajectory_color(&self) -> Color;
 }
@@ -92,20 +97,18 @@ pub trait MovingObject: Object + ObjectMotion {
 impl<T: Object + ObjectMotion> MovingObject for T {
     fn update_position(
         &mut self,
-        velocity_change: VectorValue<KilometersPerSecond>,
-        time_interval: Quantity<Seconds>,
+        position: Point<Quantity<Kilometers>>,
+        velocity: VectorValue<KilometersPerSecond>,
     ) {
-        self.movement_mut().update_position(
-            velocity_change,
-            time_interval,
-        );
+        self.movement_mut()
+            .update_position(position, velocity);
     }
 
     fn trajectory(
         &self,
         step: u32,
         scale: f32,
-    ) -> Box<dyn Iterator<Item = Point> + '_> {
+    ) -> Box<dyn Iterator<Item = Point<f32>> + '_> {
         Box::new(self.movement().trajectory(step, scale))
     }
 
@@ -113,27 +116,3 @@ impl<T: Object + ObjectMotion> MovingObject for T {
         self.movement().trajectory_color()
     }
 }
-
-/// Типаж для отображения информации об объекте
-pub trait ObjectView {
-    /// Картинка
-    fn image_view(&self) -> &image::Handle;
-    /// Название
-    fn name_view(&self) -> String;
-    /// Скорость
-    fn velocity_view(&self) -> String;
-}
-
-impl<T: ObjectMotion + Object> ObjectView for T {
-    fn image_view(&self) -> &image::Handle {
-        self.image()
-    }
-
-    fn name_view(&self) -> String {
-        self.name().to_string()
-    }
-
-    fn velocity_view(&self) -> String {
-        self.movement().velocity().value.to_string()
-    }
-}
@@ -0,0 +1,204 @@
+use gset::Getset;
+
+use crate::util::{
+    color::Color,
+    data::snapshot::MovementSnapshot,
+    data::update::MovementUpdate,
+    geometry::{point::Point, vector::Vector},
+    objects::movement::trajectory::Trajectory,
+    physics::{
+        orbit::StateVectors,
+        quantities::{
+            Quantity,
+            quantity_units::{
+                Kilometers, KilometersPerSecond,
+            },
+        },
+        vector::VectorValue,
+    },
+};
+
+pub mod trajectory;
+
+/// Структура для значений двигающегося вокруг Солнца объекта
+#[derive(Getset)]
+pub struct ObjectMovement {
+    /// Скорость объекта
+    velocity: VectorValue<KilometersPerSecond>,
+    /// Позиция объекта
+    #[getset(get_copy, vis = "pub")]
+    position: Point<Quantity<Kilometers>>,
+    /// Траектория объекта
+    trajectory: Trajectory,
+}
+
+impl ObjectMovement {
+    /// Новый экземпляр из начального вектора состояния
+    pub fn new(
+        initial_state: StateVectors,
+        trajectory_color: Color,
+    ) -> Self {
+        let StateVectors { position, velocity } =
+            initial_state;
+
+        Self {
+            trajectory: Trajectory::new(
+                velocity.unit_vector,
+                position,
+                trajectory_color,
+            ),
+            velocity,
+            position,
+        }
+    }
+}
+
+impl ObjectMovement {
+    pub fn velocity(
+        &self,
+    ) -> VectorValue<KilometersPerSecond> {
+        self.velocity.clone()
+    }
+
+    pub fn trajectory_color(&self) -> Color {
+        self.trajectory.color()
+    }
+
+    /// Масштабированая траектория с пропуском некоторых позиций
+    pub fn trajectory(
+        &self,
+        step: u32,
+        scale: f32,
+    ) -> impl Iterator<Item = Point<f32>> + '_ {
+        self.trajectory.positions(step, scale)
+    }
+}
+
+impl ObjectMovement {
+    /// Перенос в другую систему отсчёта, траектория начинается заново
+    pub fn shift_frame(
+        &mut self,
+        position_shift: Vector<f64>,
+        velocity_shift: Vector<f64>,
+    ) {
+        let Point { x, y } = self.position;
+
+        let state = StateVectors::new(
+            Vector::new(x.value(), y.value())
+                + position_shift,
+            self.velocity.to_vector() + velocity_shift,
+        );
+
+        *self = Self::new(state, self.trajectory_color());
+    }
+}
+
+impl ObjectMovement {
+    /// Снимок движения, без траектории она начинается
+    /// заново с текущей позиции
+    pub fn snapshot(
+        &self,
+        with_trajectory: bool,
+    ) -> MovementSnapshot {
+        let Point { x, y } = self.position;
+
+        MovementSnapshot {
+            position: Point::new(x.value(), y.value()),
+            velocity: self.velocity.to_vector(),
+            trajectory_color: self.trajectory.color(),
+            trajectory: with_trajectory
+                .then(|| self.trajectory.clone()),
+        }
+    }
+
+    /// Восстановление движения из снимка
+    pub fn from_snapshot(
+        snapshot: MovementSnapshot,
+    ) -> Self {
+        let state = snapshot.state();
+        let MovementSnapshot {
+            trajectory_color,
+            trajectory,
+            ..
+        } = snapshot;
+
+        match trajectory {
+            Some(trajectory) => {
+                let StateVectors { position, velocity } =
+                    state;
+
+                Self { velocity, position, trajectory }
+            }
+            None => Self::new(state, trajectory_color),
+        }
+    }
+}
+
+impl ObjectMovement {
+    /// Обновление позиции и скорости после движения
+    pub fn update_position(
+        &mut self,
+        position: Point<Quantity<Kilometers>>,
+        velocity: VectorValue<KilometersPerSecond>,
+    ) {
+        self.update_state(position, velocity);
+
+        self.trajectory.add_position(
+            self.position,
+            self.velocity.unit_vector,
+        );
+    }
+
+    /// Обновление позиции и скорости без записи в траекторию
+    pub fn update_state(
+        &mut self,
+        position: Point<Quantity<Kilometers>>,
+        velocity: VectorValue<KilometersPerSecond>,
+    ) {
+        self.position = position;
+        self.velocity = velocity;
+    }
+}
+
+impl ObjectMovement {
+    /// Количество позиций, добавленных в траекторию за всё время
+    pub fn added_positions_count(&self) -> u64 {
+        self.trajectory.added_count()
+    }
+
+    /// Изменение движения после того, как в траекторию
+    /// было добавлено `added_count` позиций
+    pub fn update_since(
+        &self,
+        added_count: u64,
+    ) -> MovementUpdate {
+        let Point { x, y } = self.position;
+
+        MovementUpdate {
+            position: Point::new(x.value(), y.value()),
+            velocity: self.velocity.to_vector(),
+            trajectory: self
+                .trajectory
+                .update_since(added_count),
+        }
+    }
+
+    /// Применение изменения движения к копии
+    pub fn apply_update(&mut self, update: MovementUpdate) {
+        let MovementUpdate {
+            position,
+            velocity,
+            trajectory,
+        } = update;
+
+        let StateVectors { position, velocity } =
+            StateVectors::new(
+                Vector::new(position.x, position.y),
+                velocity,
+            );
+
+        self.position = position;
+        self.velocity = velocity;
+        self.trajectory.apply_update(trajectory);
+    }
+}
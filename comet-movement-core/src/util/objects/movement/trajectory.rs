@@ -0,0 +1,438 @@
+use gset::Getset;
+use num_traits::Float;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use tap::Tap;
+
+use crate::util::{
+    color::Color,
+    geometry::{point::Point, vector::Vector},
+    physics::quantities::{
+        Quantity, point_without_quantity_units,
+        quantity_units::Kilometers,
+    },
+};
+
+/// Наименьший поворот направления движения (в радианах),
+/// после которого в траекторию записывается новая позиция
+const MINIMUM_TURN: f64 = 1. * std::f64::consts::PI / 180.;
+/// Наибольшее количество записанных позиций
+const MAXIMUM_LENGTH: usize = 10_000;
+
+/// Траектория двигающегося объекта
+#[derive(Clone, Getset, Serialize, Deserialize)]
+pub struct Trajectory {
+    /// Замкнута ли траектория объекта
+    is_closed: bool,
+    closing: Option<TrajectoryClosing>,
+    /// Цвет траектории
+    #[getset(get_copy, vis = "pub")]
+    color: Color,
+    /// Позиции тела, записанные при повороте направления движения
+    positions: VecDeque<Point<f64>>,
+    /// Направление движения (единичный вектор) в последней записанной позиции
+    #[serde(default)]
+    sampled_direction: Vector<f64>,
+    /// Текущая позиция тела, которой заканчивается траектория
+    #[serde(default)]
+    current_position: Option<Point<f64>>,
+    /// Количество позиций, добавленных за всё время
+    #[serde(skip)]
+    #[getset(get_copy, vis = "pub")]
+    added_count: u64,
+}
+
+impl Trajectory {
+    pub fn new<F: Float>(
+        initial_velocity_vector: Vector<F>,
+        starting_position: Point<Quantity<Kilometers>>,
+        color: Color,
+    ) -> Self {
+        let trajectory =
+            VecDeque::new().tap_mut(|trajectory| {
+                trajectory.push_back(
+                    point_without_quantity_units(
+                        starting_position,
+                    ),
+                )
+            });
+
+        Self {
+            is_closed: false,
+            closing: Some(TrajectoryClosing::new(
+                initial_velocity_vector,
+            )),
+            color,
+            positions: trajectory,
+            sampled_direction: unit_vector(
+                initial_velocity_vector,
+            ),
+            current_position: None,
+            added_count: 1,
+        }
+    }
+}
+
+impl Trajectory {
+    /// Позиции тела с пропуском некоторых точек и масштабированием
+    /// (переводятся в `f32` только для отрисовки)
+    #[inline(always)]
+    pub fn positions(
+        &self,
+        step: u32,
+        scale: f32,
+    ) -> impl Iterator<Item = Point<f32>> + '_ {
+        let scale = scale as f64;
+        self.positions
+            .iter()
+            .step_by(step as usize)
+            .chain(&self.current_position)
+            .map(move |position| {
+                Point::new(
+                    (position.x / scale) as f32,
+                    (position.y / scale) as f32,
+                )
+            })
+    }
+}
+
+impl Trajectory {
+    /// Добавление позиции объекта после движения.
+    ///
+    /// Позиция записывается, только когда направление движения повернуло
+    /// на заметный угол, поэтому количество точек зависит от формы пути,
+    /// а не от количества тиков
+    pub fn add_position<F: Float>(
+        &mut self,
+        position: Point<Quantity<Kilometers>>,
+        velocity_vector: Vector<F>,
+    ) {
+        let position =
+            point_without_quantity_units(position);
+        let direction_vector = unit_vector(velocity_vector);
+
+        self.current_position = Some(position);
+
+        // Косинус угла поворота с последней записанной позиции
+        let turn_cosine = self.sampled_direction.x
+            * direction_vector.x
+            + self.sampled_direction.y * direction_vector.y;
+
+        if turn_cosine < MINIMUM_TURN.cos() {
+            self.positions.push_back(position);
+            self.added_count += 1;
+            self.sampled_direction = direction_vector;
+
+            // Замкнутая траектория сдвигается, незамкнутая ограничена по длине
+            if self.is_closed
+                || self.positions.len() > MAXIMUM_LENGTH
+            {
+                self.positions.pop_front();
+            }
+        }
+
+        // Траектория замкнута
+        if self.is_closed {
+            return;
+        }
+
+        let direction = Direction::from(velocity_vector);
+
+        let closing = self.closing.as_mut().unwrap();
+
+        // Изменение направления
+        if closing.direction == direction {
+            return;
+        }
+
+        match direction {
+            Direction::LeftDown => {
+                closing.is_left_down_used = true
+            }
+            Direction::LeftUp => {
+                closing.is_left_up_used = true
+            }
+            Direction::RightDown => {
+                closing.is_right_down_used = true
+            }
+            Direction::RightUp => {
+                closing.is_right_up_used = true
+            }
+        }
+
+        closing.direction = direction;
+
+        // Каждое направление уже было
+        if closing.is_left_down_used
+            && closing.is_left_up_used
+            && closing.is_right_down_used
+            && closing.is_right_up_used
+        {
+            if !closing.is_all_directions_used {
+                closing.is_all_directions_used = true;
+                return;
+            }
+
+            // Направление сменилось нужное количество раз, при этом в разные стороны
+            self.is_closed = true;
+            self.closing.take();
+        }
+    }
+}
+
+impl Trajectory {
+    /// Изменение после того, как в траекторию
+    /// было добавлено `added_count` позиций
+    pub fn update_since(
+        &self,
+        added_count: u64,
+    ) -> TrajectoryUpdate {
+        let length = self.positions.len();
+        let new_count =
+            self.added_count
+                .saturating_sub(added_count)
+                .min(length as u64) as usize;
+
+        TrajectoryUpdate {
+            is_closed: self.is_closed,
+            closing: self.closing.clone(),
+            sampled_direction: self.sampled_direction,
+            current_position: self.current_position,
+            positions: self
+                .positions
+                .range(length - new_count..)
+                .copied()
+                .collect(),
+            length,
+        }
+    }
+
+    /// Применение изменения к копии траектории
+    pub fn apply_update(
+        &mut self,
+        update: TrajectoryUpdate,
+    ) {
+        let TrajectoryUpdate {
+            is_closed,
+            closing,
+            sampled_direction,
+            current_position,
+            positions,
+            length,
+        } = update;
+
+        self.is_closed = is_closed;
+        self.closing = closing;
+        self.sampled_direction = sampled_direction;
+        self.current_position = current_position;
+        self.added_count += positions.len() as u64;
+        self.positions.extend(positions);
+
+        // Начало замкнутой траектории отбрасывается
+        let excess =
+            self.positions.len().saturating_sub(length);
+        self.positions.drain(..excess);
+    }
+}
+
+/// Изменение траектории: новые позиции и итоговая длина
+pub struct TrajectoryUpdate {
+    /// Замкнута ли траектория объекта
+    is_closed: bool,
+    closing: Option<TrajectoryClosing>,
+    /// Направление движения в последней записанной позиции
+    sampled_direction: Vector<f64>,
+    /// Текущая позиция тела
+    current_position: Option<Point<f64>>,
+    /// Позиции, добавленные после прошлого изменения
+    positions: Vec<Point<f64>>,
+    /// Длина траектории вместе с новыми позициями
+    length: usize,
+}
+
+/// Единичный вектор направления в `f64`, нулевой для нулевого вектора
+fn unit_vector<F: Float>(vector: Vector<F>) -> Vector<f64> {
+    let vector = Vector::new(
+        vector.x.to_f64().unwrap_or_default(),
+        vector.y.to_f64().unwrap_or_default(),
+    );
+    let length = vector.x.hypot(vector.y);
+
+    if length > 0. {
+        vector / length
+    } else {
+        Vector::new(0., 0.)
+    }
+}
+
+/// Значения для проверки замкнутости траектории
+#[derive(Clone, Serialize, Deserialize)]
+struct TrajectoryClosing {
+    pub direction: Direction,
+    /// Двигался ли уже влево вниз
+    pub is_left_down_used: bool,
+    /// Двигался ли уже влево вверх
+    pub is_left_up_used: bool,
+    /// Двигался ли уже вправо вниз
+    pub is_right_down_used: bool,
+    /// Двигался ли уже вправо вверх
+    pub is_right_up_used: bool,
+    /// Двигался ли уже во все направления
+    pub is_all_directions_used: bool,
+}
+
+impl TrajectoryClosing {
+    #[inline(always)]
+    pub fn new<F: Float>(
+        initial_velocity_vector: Vector<F>,
+    ) -> Self {
+        Self {
+            direction: Direction::from(
+                initial_velocity_vector,
+            ),
+            is_left_down_used: false,
+            is_left_up_used: false,
+            is_right_down_used: false,
+            is_right_up_used: false,
+            is_all_directions_used: false,
+        }
+    }
+}
+
+/// Направление движения объекта
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+enum Direction {
+    /// Влево вниз
+    LeftDown,
+    /// Влево вверх
+    LeftUp,
+    /// Вправо вниз
+    RightDown,
+    /// Вправо вверх
+    RightUp,
+}
+
+impl<F: Float> From<Vector<F>> for Direction {
+    fn from(vector: Vector<F>) -> Self {
+        match (vector.x, vector.y) {
+            (x, y)
+                if x.is_sign_positive()
+                    && y.is_sign_positive() =>
+            {
+                Self::RightDown
+            }
+            (x, y)
+                if x.is_sign_positive()
+                    && y.is_sign_negative() =>
+            {
+                Self::RightUp
+            }
+            (x, y)
+                if x.is_sign_negative()
+                    && y.is_sign_positive() =>
+            {
+                Self::LeftDown
+            }
+            _ => Self::LeftUp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Позиция в км
+    fn position(
+        x: f64,
+        y: f64,
+    ) -> Point<Quantity<Kilometers>> {
+        Point::new(
+            Quantity::new(Kilometers::new(x)),
+            Quantity::new(Kilometers::new(y)),
+        )
+    }
+
+    /// Траектория, начатая в позиции с направлением движения
+    fn trajectory(direction: Vector<f64>) -> Trajectory {
+        Trajectory::new(
+            direction,
+            position(0., 0.),
+            Color::from_rgb8(255, 255, 255),
+        )
+    }
+
+    #[test]
+    fn samples_circular_orbit_by_angle() {
+        // Одна и та же окружность за разное количество тиков
+        for ticks in [3_600, 360_000] {
+            let mut trajectory =
+                trajectory(Vector::new(0., 1.));
+
+            for tick in 1..=ticks / 2 {
+                let angle = std::f64::consts::TAU
+                    * tick as f64
+                    / ticks as f64;
+                let (sin, cos) = angle.sin_cos();
+
+                trajectory.add_position(
+                    position(cos, sin),
+                    Vector::new(-sin, cos),
+                );
+            }
+
+            // Полуокружность по точке на каждый градус поворота
+            assert!(
+                (150..=190)
+                    .contains(&trajectory.positions.len()),
+                "{} positions for {ticks} ticks",
+                trajectory.positions.len()
+            );
+        }
+    }
+
+    #[test]
+    fn ends_straight_path_at_current_position() {
+        let mut trajectory =
+            trajectory(Vector::new(1., 0.));
+
+        for tick in 1..=100_000 {
+            trajectory.add_position(
+                position(tick as f64, 0.),
+                Vector::new(1., 0.),
+            );
+        }
+
+        let positions =
+            trajectory.positions(1, 1.).collect::<Vec<_>>();
+
+        assert_eq!(
+            positions,
+            [Point::new(0., 0.), Point::new(100_000., 0.)]
+        );
+    }
+
+    #[test]
+    fn limits_length_of_open_trajectory() {
+        let mut trajectory =
+            trajectory(Vector::new(1., 0.));
+
+        // Зигзаг поворачивает на каждом тике, но не замыкается
+        for tick in 1..=3 * MAXIMUM_LENGTH {
+            let angle =
+                if tick % 2 == 0 { 10. } else { 60. };
+            let (sin, cos) = angle.to_radians().sin_cos();
+
+            trajectory.add_position(
+                position(tick as f64, 0.),
+                Vector::new(cos, sin),
+            );
+        }
+
+        assert!(!trajectory.is_closed);
+        assert_eq!(
+            trajectory.positions.len(),
+            MAXIMUM_LENGTH
+        );
+    }
+}
@@ -1,8 +1,7 @@
 use gset::Getset;
-use iced::Point;
 
 use crate::util::{
-    geometry::circle::Circle,
+    geometry::{circle::Circle, point::Point},
     objects::Object,
     physics::quantities::{
         Quantity,
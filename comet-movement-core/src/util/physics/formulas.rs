@@ -0,0 +1,94 @@
+use crate::util::{
+    geometry::vector::Vector,
+    physics::quantities::{
+        Quantity,
+        quantity_units::{
+            Kilograms, Meters,
+            NewtonMeterSquaredPerKilogramSquared,
+        },
+    },
+};
+
+/// Гравитационная постоянная
+const G: Quantity<NewtonMeterSquaredPerKilogramSquared> =
+    Quantity::new(
+        NewtonMeterSquaredPerKilogramSquared::new(
+            6.6743e-11,
+        ),
+    );
+
+/// Расчёт вектора гравитационного ускорения (в км/с²), сообщаемого объекту другим объектом
+pub fn vector_of_gravitational_acceleration(
+    position: Vector<f64>,
+    other_position: Vector<f64>,
+    other_mass: Quantity<Kilograms>,
+) -> Vector<f64> {
+    // Вектор от объекта к другому объекту в метрах
+    let distance_vector = (other_position - position) * 1e3;
+
+    // Расстояние между объектами
+    let r = Quantity::new(Meters::new(
+        distance_vector.x.hypot(distance_vector.y),
+    ));
+
+    // Если расстояние равно нулю (взаимодействие объекта с самим собой)
+    if r.value() < 1e-11 {
+        return Vector::new(0., 0.);
+    }
+
+    // Значение ускорения в м/с²
+    let acceleration = (G * other_mass / (r * r)).value();
+
+    // Перевод в км/с² вдоль единичного вектора
+    distance_vector * (acceleration / r.value() / 1e3)
+}
+
+/// Расчёт вектора рывка (производной ускорения, в км/с³), сообщаемого объекту другим объектом
+pub fn vector_of_gravitational_jerk(
+    (position, velocity): (Vector<f64>, Vector<f64>),
+    (other_position, other_velocity): (
+        Vector<f64>,
+        Vector<f64>,
+    ),
+    other_mass: Quantity<Kilograms>,
+) -> Vector<f64> {
+    // Относительные положение (м) и скорость (м/с)
+    let distance_vector = (other_position - position) * 1e3;
+    let velocity_vector = (other_velocity - velocity) * 1e3;
+
+    let r = distance_vector.x.hypot(distance_vector.y);
+
+    // Если расстояние равно нулю (взаимодействие объекта с самим собой)
+    if r < 1e-11 {
+        return Vector::new(0., 0.);
+    }
+
+    let gm = (G * other_mass).value();
+
+    // Скорость сближения, делённая на расстояние
+    let approach = (distance_vector.x * velocity_vector.x
+        + distance_vector.y * velocity_vector.y)
+        / (r * r);
+
+    // Перевод в км/с³
+    (velocity_vector - distance_vector * (3. * approach))
+        * (gm / (r * r * r) / 1e3)
+}
+
+/// Расчёт гравитационного параметра (в км³/с²) объекта
+pub fn standard_gravitational_parameter(
+    mass: Quantity<Kilograms>,
+) -> f64 {
+    (G * mass).value() * 1e-9
+}
+
+/// Радиус сферы Хилла (в км) объекта на расстоянии от центрального объекта
+pub fn hill_radius(
+    distance: f64,
+    mass: Quantity<Kilograms>,
+    central_mass: Quantity<Kilograms>,
+) -> f64 {
+    distance
+        * (mass.value() / (3. * central_mass.value()))
+            .cbrt()
+}
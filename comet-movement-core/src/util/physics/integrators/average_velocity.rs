@@ -0,0 +1,45 @@
+use crate::util::physics::{
+    integrators::{GravitationalSystem, Integrator},
+    quantities::{Quantity, quantity_units::Seconds},
+};
+
+/// Движение со средней скоростью на шаге (метод первого порядка)
+pub struct AverageVelocity;
+
+impl Integrator for AverageVelocity {
+    fn step(
+        &self,
+        system: &mut GravitationalSystem,
+        time_interval: Quantity<Seconds>,
+    ) {
+        let dt = time_interval.value_f64();
+
+        let positions = system.positions();
+        let velocities = system.velocities();
+        let accelerations =
+            system.accelerations(&positions);
+
+        // Изменение скорости за шаг
+        let velocities_changes = accelerations
+            .iter()
+            .map(|acceleration| *acceleration * dt)
+            .collect::<Vec<_>>();
+
+        // Перемещение со средней скоростью на участке
+        let new_positions = positions
+            .iter()
+            .zip(velocities.iter().zip(&velocities_changes))
+            .map(|(position, (velocity, change))| {
+                *position + (*velocity + *change / 2.) * dt
+            })
+            .collect::<Vec<_>>();
+
+        let new_velocities = velocities
+            .iter()
+            .zip(&velocities_changes)
+            .map(|(velocity, change)| *velocity + *change)
+            .collect::<Vec<_>>();
+
+        system.set_state(&new_positions, &new_velocities);
+    }
+}
@@ -0,0 +1,52 @@
+use crate::util::physics::{
+    integrators::{GravitationalSystem, Integrator},
+    quantities::{Quantity, quantity_units::Seconds},
+};
+
+/// Метод чехарды в форме "сдвиг-толчок-сдвиг" (симплектический, второго порядка)
+pub struct Leapfrog;
+
+impl Integrator for Leapfrog {
+    fn step(
+        &self,
+        system: &mut GravitationalSystem,
+        time_interval: Quantity<Seconds>,
+    ) {
+        let dt = time_interval.value_f64();
+
+        let velocities = system.velocities();
+
+        // Сдвиг на половину шага
+        let half_step_positions = system
+            .positions()
+            .iter()
+            .zip(&velocities)
+            .map(|(position, velocity)| {
+                *position + *velocity * (dt / 2.)
+            })
+            .collect::<Vec<_>>();
+
+        let accelerations =
+            system.accelerations(&half_step_positions);
+
+        // Толчок на полный шаг
+        let new_velocities = velocities
+            .iter()
+            .zip(&accelerations)
+            .map(|(velocity, acceleration)| {
+                *velocity + *acceleration * dt
+            })
+            .collect::<Vec<_>>();
+
+        // Сдвиг на оставшуюся половину шага
+        let new_positions = half_step_positions
+            .iter()
+            .zip(&new_velocities)
+            .map(|(position, velocity)| {
+                *position + *velocity * (dt / 2.)
+            })
+            .collect::<Vec<_>>();
+
+        system.set_state(&new_positions, &new_velocities);
+    }
+}
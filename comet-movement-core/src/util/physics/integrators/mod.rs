@@ -0,0 +1,565 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::{
+    geometry::{point::Point, vector::Vector},
+    objects::values::ObjectGravitationalForceValues,
+    physics::{
+        formulas::{
+            vector_of_gravitational_acceleration,
+            vector_of_gravitational_jerk,
+        },
+        integrators::{
+            average_velocity::AverageVelocity,
+            leapfrog::Leapfrog, runge_kutta::RungeKutta4,
+            velocity_verlet::VelocityVerlet,
+        },
+        quantities::{
+            Quantity,
+            quantity_units::{
+                Kilograms, Kilometers, KilometersPerSecond,
+                Seconds,
+            },
+        },
+        vector::VectorValue,
+    },
+};
+
+pub mod average_velocity;
+pub mod leapfrog;
+pub mod runge_kutta;
+pub mod step_control;
+pub mod velocity_verlet;
+
+/// Типаж численного интегратора движения
+pub trait Integrator {
+    /// Продвижение системы на промежуток времени
+    fn step(
+        &self,
+        system: &mut GravitationalSystem,
+        time_interval: Quantity<Seconds>,
+    );
+}
+
+/// Тело гравитационной системы
+#[derive(Clone, Copy)]
+pub struct Body {
+    /// Позиция в км
+    position: Vector<f64>,
+    /// Скорость в км/с
+    velocity: Vector<f64>,
+    /// Масса
+    mass: Quantity<Kilograms>,
+    /// Двигается ли тело
+    is_moving: bool,
+    /// Притягивает ли тело остальные
+    is_massive: bool,
+}
+
+impl Body {
+    /// Новое двигающееся тело
+    pub fn moving(
+        values: ObjectGravitationalForceValues,
+        velocity: VectorValue<KilometersPerSecond>,
+    ) -> Self {
+        let Point { x, y } = values.position();
+
+        Self {
+            position: Vector::new(
+                x.value_f64(),
+                y.value_f64(),
+            ),
+            velocity: velocity.to_vector(),
+            mass: values.mass(),
+            is_moving: true,
+            is_massive: true,
+        }
+    }
+
+    /// Новая пробная частица: двигающееся тело,
+    /// которое притягивается, но само не притягивает
+    pub fn test_particle(
+        values: ObjectGravitationalForceValues,
+        velocity: VectorValue<KilometersPerSecond>,
+    ) -> Self {
+        Self {
+            is_massive: false,
+            ..Self::moving(values, velocity)
+        }
+    }
+
+    /// Новое неподвижное тело (только источник притяжения)
+    pub fn fixed(
+        values: ObjectGravitationalForceValues,
+    ) -> Self {
+        let Point { x, y } = values.position();
+
+        Self {
+            position: Vector::new(
+                x.value_f64(),
+                y.value_f64(),
+            ),
+            velocity: Vector::new(0., 0.),
+            mass: values.mass(),
+            is_moving: false,
+            is_massive: true,
+        }
+    }
+}
+
+impl Body {
+    /// Позиция тела
+    pub fn position(&self) -> Point<Quantity<Kilometers>> {
+        Point {
+            x: Quantity::new(Kilometers::new(
+                self.position.x,
+            )),
+            y: Quantity::new(Kilometers::new(
+                self.position.y,
+            )),
+        }
+    }
+
+    /// Скорость тела
+    pub fn velocity(
+        &self,
+    ) -> VectorValue<KilometersPerSecond> {
+        VectorValue::from_vector(self.velocity)
+    }
+}
+
+/// Гравитационная система тел
+#[derive(Clone)]
+pub struct GravitationalSystem {
+    /// Тела системы
+    bodies: Vec<Body>,
+    /// Индексы притягивающих тел
+    massive_bodies: Vec<usize>,
+}
+
+impl GravitationalSystem {
+    pub fn new(bodies: Vec<Body>) -> Self {
+        let massive_bodies = bodies
+            .iter()
+            .enumerate()
+            .filter(|(_, body)| body.is_massive)
+            .map(|(index, _)| index)
+            .collect();
+
+        Self { bodies, massive_bodies }
+    }
+
+    #[inline(always)]
+    pub fn bodies(&self) -> &[Body] {
+        self.bodies.as_slice()
+    }
+}
+
+impl GravitationalSystem {
+    /// Текущие позиции тел
+    fn positions(&self) -> Vec<Vector<f64>> {
+        self.bodies
+            .iter()
+            .map(|body| body.position)
+            .collect()
+    }
+
+    /// Текущие скорости тел
+    fn velocities(&self) -> Vec<Vector<f64>> {
+        self.bodies
+            .iter()
+            .map(|body| body.velocity)
+            .collect()
+    }
+
+    /// Ускорения тел (в км/с²) при заданных позициях,
+    /// их сообщают только притягивающие тела
+    fn accelerations(
+        &self,
+        positions: &[Vector<f64>],
+    ) -> Vec<Vector<f64>> {
+        self.bodies
+            .iter()
+            .zip(positions)
+            .map(|(body, position)| {
+                if !body.is_moving {
+                    return Vector::new(0., 0.);
+                }
+
+                self.massive_bodies.iter().fold(
+                    Vector::new(0., 0.),
+                    |acceleration, &other| {
+                        acceleration
+                            + vector_of_gravitational_acceleration(
+                                *position,
+                                positions[other],
+                                self.bodies[other].mass,
+                            )
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Характерное время изменения движения (отношение ускорения к рывку),
+    /// минимальное среди двигающихся тел
+    fn time_scale(&self) -> Option<f64> {
+        let accelerations =
+            self.accelerations(&self.positions());
+
+        self.bodies
+            .iter()
+            .zip(&accelerations)
+            .filter(|(body, _)| body.is_moving)
+            .filter_map(|(body, acceleration)| {
+                let jerk = self.massive_bodies.iter().fold(
+                    Vector::new(0., 0.),
+                    |jerk, &other| {
+                        let other = &self.bodies[other];
+                        jerk + vector_of_gravitational_jerk(
+                            (body.position, body.velocity),
+                            (
+                                other.position,
+                                other.velocity,
+                            ),
+                            other.mass,
+                        )
+                    },
+                );
+                let jerk = jerk.x.hypot(jerk.y);
+
+                (jerk > 0.).then(|| {
+                    acceleration.x.hypot(acceleration.y)
+                        / jerk
+                })
+            })
+            .min_by(f64::total_cmp)
+    }
+
+    /// Запись новых позиций и скоростей двигающихся тел
+    fn set_state(
+        &mut self,
+        positions: &[Vector<f64>],
+        velocities: &[Vector<f64>],
+    ) {
+        self.bodies
+            .iter_mut()
+            .zip(positions.iter().zip(velocities))
+            .filter(|(body, _)| body.is_moving)
+            .for_each(|(body, (position, velocity))| {
+                body.position = *position;
+                body.velocity = *velocity;
+            });
+    }
+}
+
+/// Метод численного интегрирования
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrationMethod {
+    /// Движение со средней скоростью на шаге
+    AverageVelocity,
+    /// Скоростной алгоритм Верле
+    VelocityVerlet,
+    /// Метод чехарды
+    Leapfrog,
+    /// Классический метод Рунге-Кутты 4-го порядка
+    #[serde(rename = "rk4")]
+    RungeKutta4,
+}
+
+impl IntegrationMethod {
+    /// Интегратор метода
+    pub fn integrator(self) -> &'static dyn Integrator {
+        match self {
+            Self::AverageVelocity => &AverageVelocity,
+            Self::VelocityVerlet => &VelocityVerlet,
+            Self::Leapfrog => &Leapfrog,
+            Self::RungeKutta4 => &RungeKutta4,
+        }
+    }
+
+    /// Обратим ли метод во времени: шаг назад
+    /// точно отменяет шаг вперёд
+    pub fn is_time_reversible(self) -> bool {
+        match self {
+            Self::VelocityVerlet | Self::Leapfrog => true,
+            Self::AverageVelocity | Self::RungeKutta4 => {
+                false
+            }
+        }
+    }
+
+    /// Метод для интегрирования назад во времени:
+    /// необратимые методы заменяются скоростным Верле
+    pub fn time_reversible(self) -> Self {
+        if self.is_time_reversible() {
+            self
+        } else {
+            Self::VelocityVerlet
+        }
+    }
+
+    /// Переключение на следующий метод
+    pub fn set_next(&mut self) {
+        *self = match self {
+            Self::AverageVelocity => Self::VelocityVerlet,
+            Self::VelocityVerlet => Self::Leapfrog,
+            Self::Leapfrog => Self::RungeKutta4,
+            Self::RungeKutta4 => Self::AverageVelocity,
+        }
+    }
+}
+
+impl FromStr for IntegrationMethod {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "average_velocity" => Ok(Self::AverageVelocity),
+            "velocity_verlet" => Ok(Self::VelocityVerlet),
+            "leapfrog" => Ok(Self::Leapfrog),
+            "rk4" => Ok(Self::RungeKutta4),
+            _ => Err(format!(
+                "Unknown integration method: {value}"
+            )),
+        }
+    }
+}
+
+impl Display for IntegrationMethod {
+    fn fmt(
+        &self,
+        fmt: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(
+            fmt,
+            "{}",
+            match self {
+                Self::AverageVelocity => "Средняя скорость",
+                Self::VelocityVerlet => "Верле",
+                Self::Leapfrog => "Чехарда",
+                Self::RungeKutta4 => "Рунге-Кутта 4",
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use super::*;
+    use crate::util::physics::{
+        formulas::standard_gravitational_parameter,
+        orbit::{
+            PerihelionOrbit,
+            tests::{ASTRONOMICAL_UNIT, SUN_MASS},
+        },
+    };
+
+    /// Шагов на оборот при проверке энергии
+    const STEPS_PER_ORBIT: u32 = 1000;
+
+    /// Все методы интегрирования
+    const METHODS: [IntegrationMethod; 4] = [
+        IntegrationMethod::AverageVelocity,
+        IntegrationMethod::VelocityVerlet,
+        IntegrationMethod::Leapfrog,
+        IntegrationMethod::RungeKutta4,
+    ];
+
+    /// Орбита с перигелием 1 а. е. и эксцентриситетом 0.5
+    fn orbit() -> PerihelionOrbit {
+        PerihelionOrbit::new(
+            Quantity::new(Kilometers::new(
+                ASTRONOMICAL_UNIT,
+            )),
+            0.5,
+            0.,
+            0.,
+            0.,
+        )
+    }
+
+    /// Гравитационный параметр Солнца в км³/с²
+    fn mu() -> f64 {
+        standard_gravitational_parameter(Quantity::new(
+            Kilograms::new(SUN_MASS),
+        ))
+    }
+
+    /// Период обращения по орбите в секундах
+    fn period() -> f64 {
+        // Большая полуось
+        let a = ASTRONOMICAL_UNIT / (1. - 0.5);
+        TAU * (a * a * a / mu()).sqrt()
+    }
+
+    /// Неподвижное Солнце и пробная частица в перигелии орбиты
+    fn kepler_system() -> GravitationalSystem {
+        let mass = Quantity::new(Kilograms::new(SUN_MASS));
+        let state = orbit().state_vectors(
+            mass,
+            Quantity::new(Seconds::new(0.)),
+        );
+        let Point { x, y } = state.position;
+
+        GravitationalSystem::new(vec![
+            Body {
+                position: Vector::new(0., 0.),
+                velocity: Vector::new(0., 0.),
+                mass,
+                is_moving: false,
+                is_massive: true,
+            },
+            Body {
+                position: Vector::new(x.value(), y.value()),
+                velocity: state.velocity.to_vector(),
+                mass: Quantity::new(Kilograms::new(1e13)),
+                is_moving: true,
+                is_massive: false,
+            },
+        ])
+    }
+
+    /// Удельная энергия частицы в км²/с²
+    fn energy(system: &GravitationalSystem) -> f64 {
+        let Body { position, velocity, .. } =
+            system.bodies()[1];
+
+        (velocity.x * velocity.x + velocity.y * velocity.y)
+            / 2.
+            - mu() / position.x.hypot(position.y)
+    }
+
+    /// Продвижение системы на заданное число равных шагов
+    fn advance(
+        method: IntegrationMethod,
+        system: &mut GravitationalSystem,
+        duration: f64,
+        steps: u32,
+    ) {
+        let step = Quantity::new(Seconds::new(
+            duration / steps as f64,
+        ));
+
+        (0..steps).for_each(|_| {
+            method.integrator().step(system, step)
+        });
+    }
+
+    /// Наибольшие относительные ошибки энергии на каждом из оборотов
+    fn energy_errors(
+        method: IntegrationMethod,
+        orbits: u32,
+    ) -> Vec<f64> {
+        let mut system = kepler_system();
+        let initial_energy = energy(&system);
+
+        (0..orbits)
+            .map(|_| {
+                (0..STEPS_PER_ORBIT)
+                    .map(|_| {
+                        advance(
+                            method,
+                            &mut system,
+                            period()
+                                / STEPS_PER_ORBIT as f64,
+                            1,
+                        );
+                        ((energy(&system) - initial_energy)
+                            / initial_energy)
+                            .abs()
+                    })
+                    .fold(0., f64::max)
+            })
+            .collect()
+    }
+
+    /// Ошибка позиции после половины оборота за заданное число шагов
+    fn position_error(
+        method: IntegrationMethod,
+        steps: u32,
+    ) -> f64 {
+        let mut system = kepler_system();
+        advance(method, &mut system, period() / 2., steps);
+
+        let expected = orbit().state_vectors(
+            Quantity::new(Kilograms::new(SUN_MASS)),
+            Quantity::new(Seconds::new(period() / 2.)),
+        );
+        let Point { x, y } = expected.position;
+        let position = system.bodies()[1].position;
+
+        (position.x - x.value())
+            .hypot(position.y - y.value())
+    }
+
+    /// Порядок точности метода
+    fn expected_order(method: IntegrationMethod) -> f64 {
+        match method {
+            IntegrationMethod::AverageVelocity => 1.,
+            IntegrationMethod::VelocityVerlet
+            | IntegrationMethod::Leapfrog => 2.,
+            IntegrationMethod::RungeKutta4 => 4.,
+        }
+    }
+
+    #[test]
+    fn symplectic_methods_keep_energy_bounded() {
+        for method in METHODS
+            .into_iter()
+            .filter(|method| method.is_time_reversible())
+        {
+            let errors = energy_errors(method, 20);
+
+            // Ошибка колеблется внутри оборота, но не растёт
+            assert!(
+                errors[0] < 1e-3,
+                "{method}: {errors:?}"
+            );
+            assert!(
+                (errors[19] - errors[0]).abs()
+                    < 1e-3 * errors[0],
+                "{method}: {errors:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn other_methods_drift_in_energy() {
+        let errors = energy_errors(
+            IntegrationMethod::RungeKutta4,
+            20,
+        );
+        assert!(errors[19] < 1e-7, "{errors:?}");
+        assert!(errors[19] > 3. * errors[0], "{errors:?}");
+
+        let errors = energy_errors(
+            IntegrationMethod::AverageVelocity,
+            20,
+        );
+        assert!(errors[19] > 5. * errors[0], "{errors:?}");
+    }
+
+    #[test]
+    fn converges_with_expected_order() {
+        for method in METHODS {
+            // Ошибка убывает в 2^p раз при вдвое меньшем шаге
+            let order = (position_error(method, 200)
+                / position_error(method, 400))
+            .log2();
+
+            assert!(
+                (order - expected_order(method)).abs()
+                    < 0.15,
+                "{method}: order {order}"
+            );
+        }
+    }
+}
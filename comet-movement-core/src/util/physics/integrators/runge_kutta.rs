@@ -0,0 +1,119 @@
+use crate::util::{
+    geometry::vector::Vector,
+    physics::{
+        integrators::{GravitationalSystem, Integrator},
+        quantities::{Quantity, quantity_units::Seconds},
+    },
+};
+
+/// Классический метод Рунге-Кутты четвёртого порядка
+pub struct RungeKutta4;
+
+impl RungeKutta4 {
+    /// Сдвиг значений на долю шага по производным
+    fn shifted(
+        values: &[Vector<f64>],
+        derivatives: &[Vector<f64>],
+        dt: f64,
+    ) -> Vec<Vector<f64>> {
+        values
+            .iter()
+            .zip(derivatives)
+            .map(|(value, derivative)| {
+                *value + *derivative * dt
+            })
+            .collect()
+    }
+
+    /// Взвешенная сумма производных четырёх стадий
+    fn weighted_sum(
+        values: &[Vector<f64>],
+        [k1, k2, k3, k4]: [&[Vector<f64>]; 4],
+        dt: f64,
+    ) -> Vec<Vector<f64>> {
+        (0..values.len())
+            .map(|index| {
+                values[index]
+                    + (k1[index]
+                        + k2[index] * 2.
+                        + k3[index] * 2.
+                        + k4[index])
+                        * (dt / 6.)
+            })
+            .collect()
+    }
+}
+
+impl Integrator for RungeKutta4 {
+    fn step(
+        &self,
+        system: &mut GravitationalSystem,
+        time_interval: Quantity<Seconds>,
+    ) {
+        let dt = time_interval.value_f64();
+
+        let positions = system.positions();
+        let velocities = system.velocities();
+
+        // Первая стадия
+        let k1_positions = velocities.clone();
+        let k1_velocities =
+            system.accelerations(&positions);
+
+        // Вторая стадия
+        let k2_positions = Self::shifted(
+            &velocities,
+            &k1_velocities,
+            dt / 2.,
+        );
+        let k2_velocities =
+            system.accelerations(&Self::shifted(
+                &positions,
+                &k1_positions,
+                dt / 2.,
+            ));
+
+        // Третья стадия
+        let k3_positions = Self::shifted(
+            &velocities,
+            &k2_velocities,
+            dt / 2.,
+        );
+        let k3_velocities =
+            system.accelerations(&Self::shifted(
+                &positions,
+                &k2_positions,
+                dt / 2.,
+            ));
+
+        // Четвёртая стадия
+        let k4_positions =
+            Self::shifted(&velocities, &k3_velocities, dt);
+        let k4_velocities = system.accelerations(
+            &Self::shifted(&positions, &k3_positions, dt),
+        );
+
+        let new_positions = Self::weighted_sum(
+            &positions,
+            [
+                &k1_positions,
+                &k2_positions,
+                &k3_positions,
+                &k4_positions,
+            ],
+            dt,
+        );
+        let new_velocities = Self::weighted_sum(
+            &velocities,
+            [
+                &k1_velocities,
+                &k2_velocities,
+                &k3_velocities,
+                &k4_velocities,
+            ],
+            dt,
+        );
+
+        system.set_state(&new_positions, &new_velocities);
+    }
+}
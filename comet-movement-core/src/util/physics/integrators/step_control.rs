@@ -0,0 +1,234 @@
+use crate::util::physics::{
+    integrators::{GravitationalSystem, Integrator},
+    quantities::{Quantity, quantity_units::Seconds},
+};
+
+/// Наибольшее количество попыток согласовать число подшагов
+/// с состоянием в конце промежутка
+const MAXIMUM_ITERATIONS: u32 = 4;
+
+/// Управление шагом интегрирования при тесных сближениях
+#[derive(Clone, Copy)]
+pub struct StepControl {
+    /// Доля характерного времени движения, допустимая для одного шага
+    accuracy: f64,
+    /// Максимальное количество подшагов за промежуток времени
+    maximum_substeps: u32,
+}
+
+impl StepControl {
+    #[inline(always)]
+    pub fn new(
+        accuracy: f64,
+        maximum_substeps: u32,
+    ) -> Self {
+        Self { accuracy, maximum_substeps }
+    }
+}
+
+impl StepControl {
+    /// Продвижение системы на промежуток времени с разбиением на равные подшаги.
+    ///
+    /// Количество подшагов определяется наименьшим отношением ускорения
+    /// к рывку среди тел, поэтому шаг дробится только тогда,
+    /// когда какое-то тело проходит близко к другому.
+    /// Оно выбирается симметрично по состояниям в начале и в конце
+    /// промежутка, поэтому шаг назад проходит те же подшаги, что и шаг вперёд,
+    /// и обратимые методы возвращают систему в исходное состояние.
+    /// Отрицательный промежуток продвигает систему назад во времени
+    pub fn integrate(
+        &self,
+        integrator: &dyn Integrator,
+        system: &mut GravitationalSystem,
+        time_interval: Quantity<Seconds>,
+    ) {
+        let initial_substeps =
+            self.substeps(system, time_interval);
+        let mut substeps = initial_substeps;
+
+        for iteration in 1.. {
+            let mut trial = system.clone();
+            Self::advance(
+                integrator,
+                &mut trial,
+                time_interval,
+                substeps,
+            );
+
+            // Подшагов столько, сколько нужно на более тесном из концов промежутка
+            let needed_substeps = initial_substeps
+                .max(self.substeps(&trial, time_interval));
+
+            if needed_substeps == substeps
+                || iteration == MAXIMUM_ITERATIONS
+            {
+                *system = trial;
+                return;
+            }
+
+            substeps = needed_substeps;
+        }
+    }
+
+    /// Количество подшагов, нужное для промежутка времени в текущем состоянии
+    fn substeps(
+        &self,
+        system: &GravitationalSystem,
+        time_interval: Quantity<Seconds>,
+    ) -> u32 {
+        let time_interval = time_interval.value_f64().abs();
+        let maximum_substeps = self.maximum_substeps.max(1);
+
+        system.time_scale().map_or(1, |time_scale| {
+            (time_interval / (time_scale * self.accuracy))
+                .ceil()
+                .clamp(1., maximum_substeps as f64)
+                as u32
+        })
+    }
+
+    /// Продвижение системы равными подшагами
+    fn advance(
+        integrator: &dyn Integrator,
+        system: &mut GravitationalSystem,
+        time_interval: Quantity<Seconds>,
+        substeps: u32,
+    ) {
+        let step = Quantity::new(Seconds::new(
+            time_interval.value_f64() / substeps as f64,
+        ));
+
+        for _ in 0..substeps {
+            integrator.step(system, step);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{
+        geometry::{point::Point, vector::Vector},
+        physics::{
+            integrators::{
+                Body, leapfrog::Leapfrog,
+                velocity_verlet::VelocityVerlet,
+            },
+            orbit::{
+                PerihelionOrbit,
+                tests::{ASTRONOMICAL_UNIT, SUN_MASS},
+            },
+            quantities::quantity_units::{
+                Kilograms, Kilometers,
+            },
+        },
+    };
+
+    /// Неподвижное Солнце и комета за полдня до тесного прохождения перигелия
+    fn sungrazer() -> GravitationalSystem {
+        let mass = Quantity::new(Kilograms::new(SUN_MASS));
+        let state = PerihelionOrbit::new(
+            Quantity::new(Kilometers::new(
+                0.005 * ASTRONOMICAL_UNIT,
+            )),
+            0.9999,
+            0.,
+            0.,
+            0.,
+        )
+        .state_vectors(
+            mass,
+            Quantity::new(Seconds::new(-43_200.)),
+        );
+        let Point { x, y } = state.position;
+
+        GravitationalSystem::new(vec![
+            Body {
+                position: Vector::new(0., 0.),
+                velocity: Vector::new(0., 0.),
+                mass,
+                is_moving: false,
+                is_massive: true,
+            },
+            Body {
+                position: Vector::new(x.value(), y.value()),
+                velocity: state.velocity.to_vector(),
+                mass: Quantity::new(Kilograms::new(1e13)),
+                is_moving: true,
+                is_massive: false,
+            },
+        ])
+    }
+
+    /// Продвижение на тики по 60 секунд, возвращается наибольшее число подшагов
+    fn run(
+        control: StepControl,
+        integrator: &dyn Integrator,
+        system: &mut GravitationalSystem,
+        ticks: u32,
+        direction: f64,
+    ) -> u32 {
+        let tick =
+            Quantity::new(Seconds::new(60. * direction));
+
+        (0..ticks)
+            .map(|_| {
+                let substeps =
+                    control.substeps(system, tick);
+                control.integrate(integrator, system, tick);
+                substeps
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn retraces_forward_run_backward() {
+        let control = StepControl::new(0.01, 1000);
+        let integrators: [&dyn Integrator; 2] =
+            [&VelocityVerlet, &Leapfrog];
+
+        for integrator in integrators {
+            let mut system = sungrazer();
+            let initial = system.bodies()[1];
+
+            // Сутки вперёд через перигелий и обратно
+            let maximum_substeps = run(
+                control,
+                integrator,
+                &mut system,
+                1440,
+                1.,
+            );
+            run(
+                control,
+                integrator,
+                &mut system,
+                1440,
+                -1.,
+            );
+
+            // У перигелия тик действительно дробился
+            assert!(maximum_substeps > 1);
+
+            let body = system.bodies()[1];
+            let position_error =
+                (body.position - initial.position).x.hypot(
+                    (body.position - initial.position).y,
+                );
+            let velocity_error =
+                (body.velocity - initial.velocity).x.hypot(
+                    (body.velocity - initial.velocity).y,
+                );
+
+            assert!(
+                position_error < 1e-5,
+                "position error {position_error} km"
+            );
+            assert!(
+                velocity_error < 1e-10,
+                "velocity error {velocity_error} km/s"
+            );
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::util::physics::{
+    integrators::{GravitationalSystem, Integrator},
+    quantities::{Quantity, quantity_units::Seconds},
+};
+
+/// Скоростной алгоритм Верле (симплектический, второго порядка)
+pub struct VelocityVerlet;
+
+impl Integrator for VelocityVerlet {
+    fn step(
+        &self,
+        system: &mut GravitationalSystem,
+        time_interval: Quantity<Seconds>,
+    ) {
+        let dt = time_interval.value_f64();
+
+        let positions = system.positions();
+        let velocities = system.velocities();
+        let accelerations =
+            system.accelerations(&positions);
+
+        // Новые позиции по текущим скорости и ускорению
+        let new_positions = positions
+            .iter()
+            .zip(velocities.iter().zip(&accelerations))
+            .map(|(position, (velocity, acceleration))| {
+                *position
+                    + *velocity * dt
+                    + *acceleration * (dt * dt / 2.)
+            })
+            .collect::<Vec<_>>();
+
+        let new_accelerations =
+            system.accelerations(&new_positions);
+
+        // Новые скорости по среднему ускорению на шаге
+        let new_velocities = velocities
+            .iter()
+            .zip(
+                accelerations
+                    .iter()
+                    .zip(&new_accelerations),
+            )
+            .map(
+                |(
+                    velocity,
+                    (acceleration, new_acceleration),
+                )| {
+                    *velocity
+                        + (*acceleration
+                            + *new_acceleration)
+                            * (dt / 2.)
+                },
+            )
+            .collect::<Vec<_>>();
+
+        system.set_state(&new_positions, &new_velocities);
+    }
+}
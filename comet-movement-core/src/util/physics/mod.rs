@@ -1,3 +1,5 @@
 pub mod formulas;
+pub mod integrators;
+pub mod orbit;
 pub mod quantities;
 pub mod vector;
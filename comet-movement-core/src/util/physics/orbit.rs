@@ -0,0 +1,660 @@
+use std::f64::consts::{PI, TAU};
+
+use crate::util::{
+    data::solar_system_data::OrbitalElementsData,
+    geometry::{point::Point, vector::Vector},
+    physics::{
+        formulas::{
+            standard_gravitational_parameter,
+            vector_of_gravitational_acceleration,
+        },
+        quantities::{
+            Quantity,
+            quantity_units::{
+                Kilograms, Kilometers, KilometersPerSecond,
+                Seconds,
+            },
+        },
+        vector::VectorValue,
+    },
+};
+
+/// Вектор состояния объекта: позиция и скорость
+#[derive(Clone)]
+pub struct StateVectors {
+    /// Позиция
+    pub position: Point<Quantity<Kilometers>>,
+    /// Скорость
+    pub velocity: VectorValue<KilometersPerSecond>,
+}
+
+impl StateVectors {
+    /// Создание из позиции в км и скорости в км/с
+    pub fn new(
+        position: Vector<f64>,
+        velocity: Vector<f64>,
+    ) -> Self {
+        Self {
+            position: Point {
+                x: Quantity::new(Kilometers::new(
+                    position.x,
+                )),
+                y: Quantity::new(Kilometers::new(
+                    position.y,
+                )),
+            },
+            velocity: VectorValue::from_vector(velocity),
+        }
+    }
+
+    /// Покой в начале координат
+    #[inline(always)]
+    pub fn at_rest() -> Self {
+        Self::new(Vector::new(0., 0.), Vector::new(0., 0.))
+    }
+}
+
+impl StateVectors {
+    /// Перевод из системы отсчёта центрального объекта
+    /// в систему отсчёта, в которой задан центральный объект
+    pub fn relative_to(
+        &self,
+        central: &StateVectors,
+    ) -> Self {
+        let Point { x, y } = self.position;
+        let Point { x: central_x, y: central_y } =
+            central.position;
+
+        Self::new(
+            Vector::new(
+                x.value() + central_x.value(),
+                y.value() + central_y.value(),
+            ),
+            self.velocity.to_vector()
+                + central.velocity.to_vector(),
+        )
+    }
+}
+
+impl StateVectors {
+    /// Предсказанные позиции в поле тяготения одного центрального объекта,
+    /// который считается неподвижным в своей начальной позиции
+    pub fn predicted_positions(
+        &self,
+        central: &StateVectors,
+        central_mass: Quantity<Kilograms>,
+        step: Quantity<Seconds>,
+        steps: usize,
+    ) -> Vec<Vector<f64>> {
+        let Point { x, y } = central.position;
+        let central_position =
+            Vector::new(x.value(), y.value());
+
+        let Point { x, y } = self.position;
+        let mut position =
+            Vector::new(x.value(), y.value());
+        // Скорость относительно центрального объекта
+        let mut velocity = self.velocity.to_vector()
+            - central.velocity.to_vector();
+
+        let dt = step.value();
+        let acceleration = |position| {
+            vector_of_gravitational_acceleration(
+                position,
+                central_position,
+                central_mass,
+            )
+        };
+
+        // Метод Верле со скоростями
+        let mut current_acceleration =
+            acceleration(position);
+        let mut positions = Vec::with_capacity(steps + 1);
+        positions.push(position);
+
+        for _ in 0..steps {
+            position += velocity * dt
+                + current_acceleration * (dt * dt / 2.);
+            let next_acceleration = acceleration(position);
+            velocity += (current_acceleration
+                + next_acceleration)
+                * (dt / 2.);
+            current_acceleration = next_acceleration;
+            positions.push(position);
+        }
+
+        positions
+    }
+}
+
+/// Кеплеровы элементы орбиты в плоскости эклиптики
+#[derive(Clone, Copy)]
+pub struct OrbitalElements {
+    /// Большая полуось
+    semi_major_axis: Quantity<Kilometers>,
+    /// Эксцентриситет
+    eccentricity: f64,
+    /// Аргумент перицентра, отсчитываемый от оси абсцисс (в радианах)
+    argument_of_periapsis: f64,
+    /// Средняя аномалия на эпоху элементов (в радианах)
+    mean_anomaly: f64,
+}
+
+impl OrbitalElements {
+    /// Углы задаются в градусах
+    pub fn new(
+        semi_major_axis: Quantity<Kilometers>,
+        eccentricity: f64,
+        argument_of_periapsis: f64,
+        mean_anomaly: f64,
+    ) -> Self {
+        Self {
+            semi_major_axis,
+            eccentricity,
+            argument_of_periapsis: argument_of_periapsis
+                .to_radians(),
+            mean_anomaly: mean_anomaly.to_radians(),
+        }
+    }
+
+    /// Круговая орбита, начинающаяся на оси абсцисс
+    #[inline(always)]
+    pub fn circular(radius: Quantity<Kilometers>) -> Self {
+        Self::new(radius, 0., 0., 0.)
+    }
+
+    /// Элементы из данных, либо круговая орбита при их отсутствии
+    pub fn from_data(
+        data: Option<&OrbitalElementsData>,
+        orbit: f64,
+    ) -> Self {
+        match data {
+            Some(data) => Self::new(
+                Quantity::new(Kilometers::new(
+                    data.semi_major_axis,
+                )),
+                data.eccentricity,
+                data.argument_of_periapsis,
+                data.mean_anomaly,
+            ),
+            None => Self::circular(Quantity::new(
+                Kilometers::new(orbit),
+            )),
+        }
+    }
+}
+
+impl OrbitalElements {
+    /// Вектор состояния относительно центрального объекта
+    /// спустя некоторое время после эпохи элементов,
+    /// масса — суммарная масса центрального и обращающегося объектов
+    pub fn state_vectors(
+        &self,
+        mass: Quantity<Kilograms>,
+        time_since_epoch: Quantity<Seconds>,
+    ) -> StateVectors {
+        let mu = standard_gravitational_parameter(mass);
+        let a = self.semi_major_axis.value();
+        let e = self.eccentricity;
+
+        // Средняя аномалия на нужный момент времени
+        let mean_motion = (mu / (a * a * a)).sqrt();
+        let mean_anomaly = (self.mean_anomaly
+            + mean_motion * time_since_epoch.value())
+        .rem_euclid(TAU);
+
+        let eccentric_anomaly =
+            solve_kepler_equation(mean_anomaly, e);
+        let (sin_e, cos_e) = eccentric_anomaly.sin_cos();
+        let semi_minor_factor = (1. - e * e).sqrt();
+
+        // Расстояние до центрального объекта
+        let r = a * (1. - e * cos_e);
+
+        // Позиция и скорость в системе отсчёта, где перицентр лежит на оси абсцисс
+        let position = Vector::new(
+            a * (cos_e - e),
+            a * semi_minor_factor * sin_e,
+        );
+        let velocity =
+            Vector::new(-sin_e, semi_minor_factor * cos_e)
+                * ((mu * a).sqrt() / r);
+
+        StateVectors::new(
+            rotate(position, self.argument_of_periapsis),
+            rotate(velocity, self.argument_of_periapsis),
+        )
+    }
+}
+
+/// Решение уравнения Кеплера `E - e sin E = M` методом Ньютона
+fn solve_kepler_equation(
+    mean_anomaly: f64,
+    eccentricity: f64,
+) -> f64 {
+    let mut eccentric_anomaly =
+        if eccentricity > 0.8 { PI } else { mean_anomaly };
+
+    for _ in 0..50 {
+        let delta = (eccentric_anomaly
+            - eccentricity * eccentric_anomaly.sin()
+            - mean_anomaly)
+            / (1. - eccentricity * eccentric_anomaly.cos());
+
+        eccentric_anomaly -= delta;
+
+        if delta.abs() < 1e-12 {
+            break;
+        }
+    }
+
+    eccentric_anomaly
+}
+
+/// Поворот вектора на угол (в радианах)
+fn rotate(vector: Vector<f64>, angle: f64) -> Vector<f64> {
+    let (sin, cos) = angle.sin_cos();
+    Vector::new(
+        vector.x * cos - vector.y * sin,
+        vector.x * sin + vector.y * cos,
+    )
+}
+
+/// Орбита, заданная перицентром и ориентацией относительно эклиптики,
+/// подходит для эллиптических, параболических и гиперболических орбит.
+/// Симуляция плоская, поэтому орбита переносится в плоскость эклиптики
+#[derive(Clone, Copy)]
+pub struct PerihelionOrbit {
+    /// Расстояние в перицентре
+    perihelion_distance: Quantity<Kilometers>,
+    /// Эксцентриситет
+    eccentricity: f64,
+    /// Аргумент перицентра (в радианах)
+    argument_of_periapsis: f64,
+    /// Долгота восходящего узла (в радианах)
+    longitude_of_ascending_node: f64,
+    /// Наклонение (в радианах)
+    inclination: f64,
+}
+
+impl PerihelionOrbit {
+    /// Углы задаются в градусах. Расстояние в перицентре и эксцентриситет
+    /// сохраняются при любом наклонении, оно задаёт только направление обращения
+    pub fn new(
+        perihelion_distance: Quantity<Kilometers>,
+        eccentricity: f64,
+        argument_of_periapsis: f64,
+        longitude_of_ascending_node: f64,
+        inclination: f64,
+    ) -> Self {
+        Self {
+            perihelion_distance,
+            eccentricity,
+            argument_of_periapsis: argument_of_periapsis
+                .to_radians(),
+            longitude_of_ascending_node:
+                longitude_of_ascending_node.to_radians(),
+            inclination: inclination.to_radians(),
+        }
+    }
+}
+
+impl PerihelionOrbit {
+    /// Вектор состояния относительно центрального объекта в плоскости эклиптики
+    /// спустя некоторое время после прохождения перицентра
+    /// (до прохождения время отрицательно)
+    pub fn state_vectors(
+        &self,
+        mass: Quantity<Kilograms>,
+        time_since_perihelion: Quantity<Seconds>,
+    ) -> StateVectors {
+        let mu = standard_gravitational_parameter(mass);
+        let q = self.perihelion_distance.value();
+        let e = self.eccentricity;
+        let t = time_since_perihelion.value();
+
+        let true_anomaly = if (e - 1.).abs() < 1e-9 {
+            parabolic_true_anomaly(mu, q, t)
+        } else if e < 1. {
+            elliptic_true_anomaly(mu, q, e, t)
+        } else {
+            hyperbolic_true_anomaly(mu, q, e, t)
+        };
+
+        // Фокальный параметр
+        let p = q * (1. + e);
+        let (sin_v, cos_v) = true_anomaly.sin_cos();
+        let r = p / (1. + e * cos_v);
+
+        // Позиция и скорость в плоскости орбиты, перицентр на оси абсцисс
+        let position = Vector::new(r * cos_v, r * sin_v);
+        let velocity = Vector::new(-sin_v, e + cos_v)
+            * (mu / p).sqrt();
+
+        StateVectors::new(
+            self.in_ecliptic_plane(position),
+            self.in_ecliptic_plane(velocity),
+        )
+    }
+
+    /// Перенос вектора из плоскости орбиты в плоскость эклиптики.
+    ///
+    /// Проекция трёхмерной орбиты на эклиптику — другое коническое сечение,
+    /// поэтому орбита поворачивается в эклиптику целиком: расстояние
+    /// в перицентре и эксцентриситет сохраняются, а наклонение задаёт
+    /// только направление обращения. Обратная орбита отражается,
+    /// и её перицентр оказывается на долготе `Ω - ω`, как при наклонении 180°
+    fn in_ecliptic_plane(
+        &self,
+        vector: Vector<f64>,
+    ) -> Vector<f64> {
+        if self.is_retrograde() {
+            rotate(
+                Vector::new(vector.x, -vector.y),
+                self.longitude_of_ascending_node
+                    - self.argument_of_periapsis,
+            )
+        } else {
+            rotate(
+                vector,
+                self.longitude_of_ascending_node
+                    + self.argument_of_periapsis,
+            )
+        }
+    }
+
+    /// Обращается ли объект против движения планет
+    #[inline(always)]
+    pub fn is_retrograde(&self) -> bool {
+        self.inclination.cos() < 0.
+    }
+}
+
+/// Прохождение перицентра: орбита и время до прохождения
+#[derive(Clone, Copy)]
+pub struct PerihelionPassage {
+    /// Орбита
+    pub orbit: PerihelionOrbit,
+    /// Время до прохождения перицентра (после прохождения отрицательно)
+    pub time_to_perihelion: Quantity<Seconds>,
+}
+
+impl PerihelionPassage {
+    /// Текущий вектор состояния на орбите вокруг центрального объекта
+    pub fn state_vectors(
+        &self,
+        central: &StateVectors,
+        central_mass: Quantity<Kilograms>,
+    ) -> StateVectors {
+        self.orbit
+            .state_vectors(
+                central_mass,
+                Quantity::new(Seconds::new(
+                    -self.time_to_perihelion.value(),
+                )),
+            )
+            .relative_to(central)
+    }
+}
+
+/// Истинная аномалия на эллиптической орбите
+fn elliptic_true_anomaly(
+    mu: f64,
+    q: f64,
+    e: f64,
+    t: f64,
+) -> f64 {
+    let a = q / (1. - e);
+    let mean_anomaly =
+        ((mu / (a * a * a)).sqrt() * t).rem_euclid(TAU);
+    let eccentric_anomaly =
+        solve_kepler_equation(mean_anomaly, e);
+
+    2. * ((1. + e).sqrt() * (eccentric_anomaly / 2.).sin())
+        .atan2(
+            (1. - e).sqrt()
+                * (eccentric_anomaly / 2.).cos(),
+        )
+}
+
+/// Истинная аномалия на параболической орбите (уравнение Баркера)
+fn parabolic_true_anomaly(mu: f64, q: f64, t: f64) -> f64 {
+    let w = 1.5 * (mu / (2. * q * q * q)).sqrt() * t;
+    let y = (w + (w * w + 1.).sqrt()).cbrt();
+
+    2. * (y - 1. / y).atan()
+}
+
+/// Истинная аномалия на гиперболической орбите
+fn hyperbolic_true_anomaly(
+    mu: f64,
+    q: f64,
+    e: f64,
+    t: f64,
+) -> f64 {
+    let a = q / (e - 1.);
+    let mean_anomaly = (mu / (a * a * a)).sqrt() * t;
+
+    // Решение уравнения `e sh H - H = M` методом Ньютона
+    let mut hyperbolic_anomaly = (mean_anomaly / e).asinh();
+
+    for _ in 0..100 {
+        let delta = (e * hyperbolic_anomaly.sinh()
+            - hyperbolic_anomaly
+            - mean_anomaly)
+            / (e * hyperbolic_anomaly.cosh() - 1.);
+
+        hyperbolic_anomaly -= delta;
+
+        if delta.abs() < 1e-12 {
+            break;
+        }
+    }
+
+    2. * (((e + 1.) / (e - 1.)).sqrt()
+        * (hyperbolic_anomaly / 2.).tanh())
+    .atan()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Масса Солнца
+    pub(crate) const SUN_MASS: f64 = 1.98841e30;
+    /// Астрономическая единица в км
+    pub(crate) const ASTRONOMICAL_UNIT: f64 = 149_597_870.7;
+
+    /// Расстояние в перицентре и эксцентриситет плоской орбиты
+    /// по вектору состояния относительно центрального объекта
+    pub(crate) fn perihelion_and_eccentricity(
+        state: &StateVectors,
+        mass: f64,
+    ) -> (f64, f64) {
+        let mu = standard_gravitational_parameter(
+            Quantity::new(Kilograms::new(mass)),
+        );
+        let Point { x, y } = state.position;
+        let position = Vector::new(x.value(), y.value());
+        let velocity = state.velocity.to_vector();
+
+        let r = position.x.hypot(position.y);
+        let speed_squared = velocity.x * velocity.x
+            + velocity.y * velocity.y;
+        let radial = position.x * velocity.x
+            + position.y * velocity.y;
+        let angular_momentum = position.x * velocity.y
+            - position.y * velocity.x;
+
+        let eccentricity_vector = (position
+            * (speed_squared - mu / r)
+            - velocity * radial)
+            / mu;
+        let e = eccentricity_vector
+            .x
+            .hypot(eccentricity_vector.y);
+
+        (
+            angular_momentum * angular_momentum
+                / (mu * (1. + e)),
+            e,
+        )
+    }
+
+    /// Вектор состояния на орбите вокруг Солнца
+    fn state_on_orbit(
+        perihelion_distance: f64,
+        eccentricity: f64,
+        inclination: f64,
+        days_since_perihelion: f64,
+    ) -> StateVectors {
+        PerihelionOrbit::new(
+            Quantity::new(Kilometers::new(
+                perihelion_distance * ASTRONOMICAL_UNIT,
+            )),
+            eccentricity,
+            30.,
+            70.,
+            inclination,
+        )
+        .state_vectors(
+            Quantity::new(Kilograms::new(SUN_MASS)),
+            Quantity::new(Seconds::new(
+                days_since_perihelion * 86_400.,
+            )),
+        )
+    }
+
+    #[test]
+    fn keeps_perihelion_distance_for_any_inclination() {
+        for inclination in [0., 60., 90., 142.5, 180.] {
+            let state =
+                state_on_orbit(1., 0.5, inclination, -40.);
+            let (q, e) = perihelion_and_eccentricity(
+                &state, SUN_MASS,
+            );
+
+            assert!(
+                (q / ASTRONOMICAL_UNIT - 1.).abs() < 1e-9,
+                "q = {q} at i = {inclination}"
+            );
+            assert!((e - 0.5).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn keeps_sungrazer_perihelion() {
+        // Околосолнечная комета семейства Крейца
+        let state =
+            state_on_orbit(0.0075, 0.9999, 142., -5.);
+        let (q, e) =
+            perihelion_and_eccentricity(&state, SUN_MASS);
+
+        assert!(
+            (q / ASTRONOMICAL_UNIT / 0.0075 - 1.).abs()
+                < 1e-6
+        );
+        assert!((e - 0.9999).abs() < 1e-9);
+    }
+
+    #[test]
+    fn turns_retrograde_orbit_clockwise() {
+        let angular_momentum = |inclination| {
+            let state =
+                state_on_orbit(1., 0.5, inclination, 10.);
+            let Point { x, y } = state.position;
+            let velocity = state.velocity.to_vector();
+
+            x.value() * velocity.y - y.value() * velocity.x
+        };
+
+        assert!(angular_momentum(89.) > 0.);
+        assert!(angular_momentum(91.) < 0.);
+    }
+
+    #[test]
+    fn keeps_passage_relative_to_moving_central() {
+        let central = StateVectors::new(
+            Vector::new(-1e6, 2e6),
+            Vector::new(0.01, -0.02),
+        );
+        let passage = PerihelionPassage {
+            orbit: PerihelionOrbit::new(
+                Quantity::new(Kilometers::new(
+                    0.3 * ASTRONOMICAL_UNIT,
+                )),
+                1.2,
+                10.,
+                200.,
+                150.,
+            ),
+            time_to_perihelion: Quantity::new(
+                Seconds::new(30. * 86_400.),
+            ),
+        };
+
+        let state = passage.state_vectors(
+            &central,
+            Quantity::new(Kilograms::new(SUN_MASS)),
+        );
+        // Обратно в систему отсчёта центрального объекта
+        let Point { x, y } = state.position;
+        let Point { x: central_x, y: central_y } =
+            central.position;
+        let relative = StateVectors::new(
+            Vector::new(
+                x.value() - central_x.value(),
+                y.value() - central_y.value(),
+            ),
+            state.velocity.to_vector()
+                - central.velocity.to_vector(),
+        );
+        let (q, e) = perihelion_and_eccentricity(
+            &relative, SUN_MASS,
+        );
+
+        assert!((q / ASTRONOMICAL_UNIT - 0.3).abs() < 1e-9);
+        assert!((e - 1.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn places_perihelion_at_longitude_of_perihelion() {
+        let direction = |inclination| {
+            let state =
+                state_on_orbit(1., 0.5, inclination, 0.);
+            let Point { x, y } = state.position;
+
+            y.value().atan2(x.value()).to_degrees()
+        };
+
+        // Ω + ω для прямой орбиты и Ω - ω для обратной
+        assert!((direction(0.) - 100.).abs() < 1e-9);
+        assert!((direction(180.) - 40.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn converts_earth_elements_to_ephemeris_position() {
+        // Средние элементы Земли на J2000 из данных Солнечной системы
+        let elements = OrbitalElements::new(
+            Quantity::new(Kilometers::new(149_598_261.2)),
+            0.01671123,
+            102.93768193,
+            357.52688973,
+        );
+        let state = elements.state_vectors(
+            Quantity::new(Kilograms::new(
+                SUN_MASS + 5.9726e24,
+            )),
+            Quantity::new(Seconds::new(0.)),
+        );
+        let Point { x, y } = state.position;
+
+        // JPL Horizons (DE441) на 01.01.2000 12:00 TDB.
+        // Элементы описывают барицентр Земли и Луны,
+        // он смещён от Земли меньше чем на 5000 км
+        let error = (x.value() + 2.649903422886233e7)
+            .hypot(y.value() - 1.446972967365423e8);
+
+        assert!(error < 2e4, "error {error} km");
+    }
+}
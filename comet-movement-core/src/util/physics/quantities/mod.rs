@@ -3,14 +3,14 @@ use std::{
     ops::{Add, Div, Mul, Sub},
 };
 
-use iced::Point;
 use num_traits::{Float, NumCast, ToPrimitive};
 
-use crate::util::physics::quantities::quantity_units::{
-    InterimQuantityUnit, Kilometers, KilometersPerSecond,
-    Meters, MetersPerSecond,
+use crate::util::{
+    geometry::point::Point,
+    physics::quantities::quantity_units::InterimQuantityUnit,
 };
 
+pub mod parsing;
 pub mod quantity_units;
 
 /// Типаж физической единицы
@@ -66,27 +66,6 @@ impl Quantity<InterimQuantityUnit> {
     }
 }
 
-impl Quantity<Kilometers> {
-    /// Перевод из км в м
-    pub fn to_meters(self) -> Quantity<Meters> {
-        Quantity::new(Meters::new(
-            (self.value() as f64) * 1e3,
-        ))
-    }
-}
-
-impl Quantity<MetersPerSecond> {
-    /// Перевод из м/с в км/с
-    #[inline(always)]
-    pub fn to_kilometers_per_second(
-        self,
-    ) -> Quantity<KilometersPerSecond> {
-        Quantity::new(KilometersPerSecond::new(
-            (self.value() / 1e3) as f32,
-        ))
-    }
-}
-
 impl<T: QuantityUnit + NewQuantity + Copy + Clone> Display
     for Quantity<T>
 {
@@ -0,0 +1,184 @@
+use std::fmt::{Display, Formatter};
+
+use crate::util::physics::quantities::{
+    Quantity,
+    quantity_units::{
+        Kilograms, Kilometers, KilometersPerSecond, Seconds,
+    },
+};
+
+/// Астрономическая единица в км
+const ASTRONOMICAL_UNIT: f64 = 149_597_870.7;
+/// Секунд в сутках
+const SECONDS_PER_DAY: f64 = 86_400.;
+/// Секунд в юлианском году
+const SECONDS_PER_YEAR: f64 = 365.25 * SECONDS_PER_DAY;
+
+/// Единицы расстояния и множители перевода в км,
+/// первая единица используется, если единица не указана
+const DISTANCE_UNITS: &[(&str, f64)] = &[
+    ("km", 1.),
+    ("км", 1.),
+    ("m", 1e-3),
+    ("м", 1e-3),
+    ("au", ASTRONOMICAL_UNIT),
+    ("а.е.", ASTRONOMICAL_UNIT),
+];
+
+/// Единицы скорости и множители перевода в км/с
+const SPEED_UNITS: &[(&str, f64)] = &[
+    ("km/s", 1.),
+    ("км/с", 1.),
+    ("m/s", 1e-3),
+    ("м/с", 1e-3),
+    ("au/d", ASTRONOMICAL_UNIT / SECONDS_PER_DAY),
+    ("а.е./сут", ASTRONOMICAL_UNIT / SECONDS_PER_DAY),
+];
+
+/// Единицы массы и множители перевода в кг
+const MASS_UNITS: &[(&str, f64)] =
+    &[("kg", 1.), ("кг", 1.), ("t", 1e3), ("т", 1e3)];
+
+/// Единицы угла и множители перевода в радианы
+const ANGLE_UNITS: &[(&str, f64)] = &[
+    ("deg", std::f64::consts::PI / 180.),
+    ("°", std::f64::consts::PI / 180.),
+    ("град", std::f64::consts::PI / 180.),
+    ("rad", 1.),
+    ("рад", 1.),
+];
+
+/// Единицы времени и множители перевода в секунды
+const DURATION_UNITS: &[(&str, f64)] = &[
+    ("d", SECONDS_PER_DAY),
+    ("сут", SECONDS_PER_DAY),
+    ("s", 1.),
+    ("с", 1.),
+    ("h", 3_600.),
+    ("ч", 3_600.),
+    ("yr", SECONDS_PER_YEAR),
+    ("год", SECONDS_PER_YEAR),
+];
+
+/// Ошибка разбора физической величины
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuantityParseError {
+    /// Пустая строка
+    Empty,
+    /// Нет числа или оно не конечно
+    InvalidNumber(String),
+    /// Неизвестная единица измерения
+    UnknownUnit(String),
+}
+
+impl Display for QuantityParseError {
+    fn fmt(
+        &self,
+        fmt: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Self::Empty => {
+                write!(fmt, "значение не задано")
+            }
+            Self::InvalidNumber(value) => {
+                write!(fmt, "«{value}» не является числом")
+            }
+            Self::UnknownUnit(unit) => {
+                write!(fmt, "неизвестная единица «{unit}»")
+            }
+        }
+    }
+}
+
+/// Разбор расстояния, по умолчанию в км
+pub fn parse_distance(
+    value: &str,
+) -> Result<Quantity<Kilometers>, QuantityParseError> {
+    parse_with_units(value, DISTANCE_UNITS)
+        .map(|value| Quantity::new(Kilometers::new(value)))
+}
+
+/// Разбор скорости, по умолчанию в км/с
+pub fn parse_speed(
+    value: &str,
+) -> Result<Quantity<KilometersPerSecond>, QuantityParseError>
+{
+    parse_with_units(value, SPEED_UNITS).map(|value| {
+        Quantity::new(KilometersPerSecond::new(value))
+    })
+}
+
+/// Разбор массы, по умолчанию в кг
+pub fn parse_mass(
+    value: &str,
+) -> Result<Quantity<Kilograms>, QuantityParseError> {
+    parse_with_units(value, MASS_UNITS)
+        .map(|value| Quantity::new(Kilograms::new(value)))
+}
+
+/// Разбор угла в радианы, по умолчанию в градусах
+pub fn parse_angle(
+    value: &str,
+) -> Result<f64, QuantityParseError> {
+    parse_with_units(value, ANGLE_UNITS)
+}
+
+/// Разбор продолжительности, по умолчанию в сутках
+pub fn parse_duration(
+    value: &str,
+) -> Result<Quantity<Seconds>, QuantityParseError> {
+    parse_with_units(value, DURATION_UNITS)
+        .map(|value| Quantity::new(Seconds::new(value)))
+}
+
+/// Разбор безразмерного числа
+pub fn parse_number(
+    value: &str,
+) -> Result<f64, QuantityParseError> {
+    parse_with_units(value, &[("", 1.)])
+}
+
+/// Разбор числа с необязательной единицей измерения
+/// и перевод в основную единицу
+fn parse_with_units(
+    value: &str,
+    units: &[(&str, f64)],
+) -> Result<f64, QuantityParseError> {
+    let value = value.trim();
+
+    if value.is_empty() {
+        return Err(QuantityParseError::Empty);
+    }
+
+    // Самое длинное начало строки, которое является числом
+    let (number, unit) = value
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain([value.len()])
+        .rev()
+        .find_map(|index| {
+            value[..index].trim().parse::<f64>().ok().map(
+                |number| (number, value[index..].trim()),
+            )
+        })
+        .filter(|(number, _)| number.is_finite())
+        .ok_or_else(|| {
+            QuantityParseError::InvalidNumber(
+                value.to_owned(),
+            )
+        })?;
+
+    if unit.is_empty() {
+        return Ok(number * units[0].1);
+    }
+
+    units
+        .iter()
+        .find(|(marking, _)| {
+            marking.eq_ignore_ascii_case(unit)
+        })
+        .map(|(_, factor)| number * factor)
+        .ok_or_else(|| {
+            QuantityParseError::UnknownUnit(unit.to_owned())
+        })
+}
@@ -2,45 +2,13 @@ use crate::util::physics::quantities::{
     NewQuantity, QuantityUnit,
 };
 
-/// Физическая единица силы: Ньютоны
-#[derive(Copy, Clone)]
-pub struct Newtons(f64);
-
-impl Newtons {
-    #[inline(always)]
-    pub const fn new(value: f64) -> Self {
-        Self(value)
-    }
-}
-
-impl NewQuantity for Newtons {
-    #[inline(always)]
-    fn new(value: Self::Value) -> Self {
-        Self::new(value)
-    }
-}
-
-impl QuantityUnit for Newtons {
-    type Value = f64;
-
-    #[inline(always)]
-    fn value(&self) -> Self::Value {
-        self.0
-    }
-
-    #[inline(always)]
-    fn marking(&self) -> &str {
-        "N"
-    }
-}
-
 /// Физическая единица расстояния: Километры
 #[derive(Copy, Clone)]
-pub struct Kilometers(f32);
+pub struct Kilometers(f64);
 
 impl Kilometers {
     #[inline(always)]
-    pub const fn new(value: f32) -> Self {
+    pub const fn new(value: f64) -> Self {
         Self(value)
     }
 }
@@ -53,7 +21,7 @@ impl NewQuantity for Kilometers {
 }
 
 impl QuantityUnit for Kilometers {
-    type Value = f32;
+    type Value = f64;
 
     #[inline(always)]
     fn value(&self) -> Self::Value {
@@ -100,11 +68,11 @@ impl QuantityUnit for Meters {
 
 /// Физическая единица скорости: Километры в секунду
 #[derive(Copy, Clone)]
-pub struct KilometersPerSecond(f32);
+pub struct KilometersPerSecond(f64);
 
 impl KilometersPerSecond {
     #[inline(always)]
-    pub const fn new(value: f32) -> Self {
+    pub const fn new(value: f64) -> Self {
         Self(value)
     }
 }
@@ -117,38 +85,6 @@ impl NewQuantity for KilometersPerSecond {
 }
 
 impl QuantityUnit for KilometersPerSecond {
-    type Value = f32;
-
-    #[inline(always)]
-    fn value(&self) -> Self::Value {
-        self.0
-    }
-
-    #[inline(always)]
-    fn marking(&self) -> &str {
-        "km/s"
-    }
-}
-
-/// Физическая единица скорости: Метры в секунду
-#[derive(Copy, Clone)]
-pub struct MetersPerSecond(f64);
-
-impl MetersPerSecond {
-    #[inline(always)]
-    pub const fn new(value: f64) -> Self {
-        Self(value)
-    }
-}
-
-impl NewQuantity for MetersPerSecond {
-    #[inline(always)]
-    fn new(value: Self::Value) -> Self {
-        Self::new(value)
-    }
-}
-
-impl QuantityUnit for MetersPerSecond {
     type Value = f64;
 
     #[inline(always)]
@@ -158,7 +94,7 @@ impl QuantityUnit for MetersPerSecond {
 
     #[inline(always)]
     fn marking(&self) -> &str {
-        "m/s"
+        "km/s"
     }
 }
 
@@ -226,45 +162,13 @@ impl QuantityUnit for NewtonMeterSquaredPerKilogramSquared {
     }
 }
 
-/// Физическая единица импульса: Килограмм на метр в секунду
-#[derive(Copy, Clone)]
-pub struct KilogramMeterPerSecond(f64);
-
-impl KilogramMeterPerSecond {
-    #[inline(always)]
-    pub const fn new(value: f64) -> Self {
-        Self(value)
-    }
-}
-
-impl NewQuantity for KilogramMeterPerSecond {
-    #[inline(always)]
-    fn new(value: Self::Value) -> Self {
-        Self::new(value)
-    }
-}
-
-impl QuantityUnit for KilogramMeterPerSecond {
-    type Value = f64;
-
-    #[inline(always)]
-    fn value(&self) -> Self::Value {
-        self.0
-    }
-
-    #[inline(always)]
-    fn marking(&self) -> &str {
-        "kg*m/s"
-    }
-}
-
 /// Физическая единица времени: Секунды
 #[derive(Copy, Clone)]
-pub struct Seconds(f32);
+pub struct Seconds(f64);
 
 impl Seconds {
     #[inline(always)]
-    pub const fn new(value: f32) -> Self {
+    pub const fn new(value: f64) -> Self {
         Self(value)
     }
 }
@@ -277,7 +181,7 @@ impl NewQuantity for Seconds {
 }
 
 impl QuantityUnit for Seconds {
-    type Value = f32;
+    type Value = f64;
 
     #[inline(always)]
     fn value(&self) -> Self::Value {
@@ -1,14 +1,13 @@
 use std::ops::{Add, Div, Mul};
 
-use iced::Vector;
-use num_traits::{Float, NumCast, ToPrimitive};
+use num_traits::{Float, ToPrimitive, Zero};
 
-use crate::util::physics::{
-    quantities::quantity_units::{
-        InterimQuantityUnit, KilometersPerSecond,
-        MetersPerSecond,
+use crate::util::{
+    geometry::vector::Vector,
+    physics::{
+        quantities::quantity_units::InterimQuantityUnit,
+        quantities::{NewQuantity, Quantity, QuantityUnit},
     },
-    quantities::{NewQuantity, Quantity, QuantityUnit},
 };
 
 /// Физический вектор
@@ -24,6 +23,19 @@ where
 impl<T: QuantityUnit + NewQuantity + Copy + Clone>
     VectorValue<T>
 {
+    /// Создание из вектора с составляющими по осям
+    pub fn from_vector(vector: Vector<T::Value>) -> Self {
+        let value = vector.x.hypot(vector.y);
+
+        let unit_vector = if value > T::Value::zero() {
+            vector / value
+        } else {
+            Vector::new(T::Value::zero(), T::Value::zero())
+        };
+
+        Self::new(Quantity::new(T::new(value)), unit_vector)
+    }
+
     #[inline(always)]
     pub fn to_vector(&self) -> Vector<T::Value> {
         Vector::new(
@@ -32,20 +44,6 @@ impl<T: QuantityUnit + NewQuantity + Copy + Clone>
         )
     }
 
-    #[inline(always)]
-    pub fn to_quantity_vector(
-        &self,
-    ) -> Vector<Quantity<T>> {
-        Vector::new(
-            Quantity::new(T::new(
-                self.value.value() * self.unit_vector.x,
-            )),
-            Quantity::new(T::new(
-                self.value.value() * self.unit_vector.y,
-            )),
-        )
-    }
-
     #[inline(always)]
     pub fn unit_vector_f64(&self) -> Vector<f64> {
         Vector::new(
@@ -55,41 +53,6 @@ impl<T: QuantityUnit + NewQuantity + Copy + Clone>
     }
 }
 
-impl VectorValue<InterimQuantityUnit> {
-    /// Перевод из неопределённой физической величины в конкретную
-    pub fn parse<
-        Q: QuantityUnit + NewQuantity + Copy + Clone,
-    >(
-        self,
-    ) -> VectorValue<Q> {
-        let unit_vector = Vector::new(
-            <Q::Value as NumCast>::from(self.unit_vector.x)
-                .unwrap(),
-            <Q::Value as NumCast>::from(self.unit_vector.y)
-                .unwrap(),
-        );
-
-        VectorValue::new(self.value.parse(), unit_vector)
-    }
-}
-
-impl VectorValue<MetersPerSecond> {
-    /// Перевод скорости из м/с в км/с
-    pub fn to_kilometers_per_second(
-        &self,
-    ) -> VectorValue<KilometersPerSecond> {
-        let unit_vector = Vector::new(
-            self.unit_vector.x as f32,
-            self.unit_vector.y as f32,
-        );
-
-        VectorValue::new(
-            self.value.to_kilometers_per_second(),
-            unit_vector,
-        )
-    }
-}
-
 impl<T, Q> Mul<Quantity<Q>> for VectorValue<T>
 where
     T: QuantityUnit + NewQuantity + Copy + Clone,
@@ -0,0 +1,285 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::physics::quantities::{
+    Quantity, quantity_units::Seconds,
+};
+
+/// Юлианская дата эпохи J2000.0 (01.01.2000 12:00:00 TDB)
+pub const J2000_JULIAN_DATE: f64 = 2_451_545.;
+
+/// Секунд в сутках
+const SECONDS_PER_DAY: f64 = 86_400.;
+
+/// Время симуляции в шкале TDB
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Time {
+    /// Секунды от эпохи J2000.0, до неё отрицательные
+    seconds_since_j2000: f64,
+}
+
+impl Time {
+    /// Время на юлианскую дату
+    pub fn new(julian_date: f64) -> Self {
+        Self {
+            seconds_since_j2000: (julian_date
+                - J2000_JULIAN_DATE)
+                * SECONDS_PER_DAY,
+        }
+    }
+}
+
+impl Default for Time {
+    /// Эпоха J2000.0
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(J2000_JULIAN_DATE)
+    }
+}
+
+impl Time {
+    /// Юлианская дата
+    pub fn julian_date(&self) -> f64 {
+        J2000_JULIAN_DATE
+            + self.seconds_since_j2000 / SECONDS_PER_DAY
+    }
+
+    #[inline(always)]
+    pub fn seconds_since_j2000(&self) -> f64 {
+        self.seconds_since_j2000
+    }
+
+    /// Добавление к текущему времени промежутка,
+    /// отрицательный промежуток отматывает время назад
+    pub fn add_seconds(
+        &mut self,
+        time_interval: Quantity<Seconds>,
+    ) {
+        self.seconds_since_j2000 += time_interval.value();
+    }
+}
+
+impl Time {
+    /// Возвращение к начальной юлианской дате
+    pub fn restart(&mut self, julian_date: f64) {
+        *self = Self::new(julian_date);
+    }
+}
+
+impl Display for Time {
+    fn fmt(
+        &self,
+        fmt: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        // Целые секунды от полуночи 01.01.2000
+        let seconds = (self.seconds_since_j2000
+            + SECONDS_PER_DAY / 2.)
+            .floor() as i64;
+        let seconds_of_day = seconds.rem_euclid(86_400);
+
+        let (year, month, day) =
+            gregorian_date(seconds.div_euclid(86_400));
+
+        write!(
+            fmt,
+            "{:02}.{:02}.{:04} {:02}:{:02}:{:02}",
+            day,
+            month,
+            year,
+            seconds_of_day / 3600,
+            seconds_of_day % 3600 / 60,
+            seconds_of_day % 60,
+        )
+    }
+}
+
+/// Дата пролептического григорианского календаря
+/// по количеству дней от 01.01.2000
+fn gregorian_date(days_since_2000: i64) -> (i64, u8, u8) {
+    // Отсчёт от 01.03.0000, чтобы високосный день был последним в году
+    let days = days_since_2000 + 730_425;
+
+    // Четырёхсотлетний цикл и день в нём
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+
+    let year_of_era = (day_of_era - day_of_era / 1460
+        + day_of_era / 36_524
+        - day_of_era / 146_096)
+        / 365;
+    let day_of_year = day_of_era
+        - (365 * year_of_era + year_of_era / 4
+            - year_of_era / 100);
+
+    // Месяцы отсчитываются от марта
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day =
+        day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+
+    let year = era * 400
+        + year_of_era
+        + if month <= 2 { 1 } else { 0 };
+
+    (year, month as u8, day as u8)
+}
+
+/// Юлианская дата по дате григорианского календаря (день может быть дробным)
+pub fn julian_date(year: i32, month: u8, day: f64) -> f64 {
+    // Январь и февраль считаются 13 и 14 месяцами предыдущего года
+    let (year, month) = if month <= 2 {
+        (year - 1, month as i32 + 12)
+    } else {
+        (year, month as i32)
+    };
+
+    let century = year.div_euclid(100);
+    let gregorian_correction =
+        2 - century + century.div_euclid(4);
+
+    (365.25 * (year + 4716) as f64).floor()
+        + (30.6001 * (month + 1) as f64).floor()
+        + day
+        + gregorian_correction as f64
+        - 1524.5
+}
+
+/// Юлианская дата по дате григорианского календаря,
+/// `None`, если такого дня в календаре нет
+pub fn checked_julian_date(
+    year: i32,
+    month: u8,
+    day: f64,
+) -> Option<f64> {
+    let julian_date = julian_date(year, month, day);
+
+    // Несуществующий день переходит в другой месяц,
+    // поэтому дата должна получиться обратно той же
+    let days_since_2000 = (julian_date
+        - (J2000_JULIAN_DATE - 0.5))
+        .floor() as i64;
+
+    (gregorian_date(days_since_2000)
+        == (year as i64, month, day.floor() as u8))
+        .then_some(julian_date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Количество дней от 01.01.2000 по дате календаря
+    fn days_since_2000(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> i64 {
+        let days = julian_date(year, month, day as f64)
+            - (J2000_JULIAN_DATE - 0.5);
+
+        assert_eq!(days.fract(), 0.);
+
+        days as i64
+    }
+
+    /// Проверка перевода даты в юлианскую и обратно
+    fn assert_round_trip(year: i32, month: u8, day: u8) {
+        assert_eq!(
+            gregorian_date(days_since_2000(
+                year, month, day
+            )),
+            (year as i64, month, day)
+        );
+    }
+
+    #[test]
+    fn converts_known_julian_dates() {
+        assert_eq!(
+            julian_date(2000, 1, 1.5),
+            J2000_JULIAN_DATE
+        );
+        assert_eq!(julian_date(2000, 2, 29.), 2_451_603.5);
+        assert_eq!(julian_date(1900, 3, 1.), 2_415_079.5);
+        // Начало юлианского периода: 24.11.4714 до н. э.
+        assert_eq!(julian_date(-4713, 11, 24.5), 0.);
+    }
+
+    #[test]
+    fn round_trips_across_j2000() {
+        assert_round_trip(1999, 12, 31);
+        assert_round_trip(2000, 1, 1);
+        assert_round_trip(2000, 1, 2);
+
+        assert_eq!(
+            Time::new(J2000_JULIAN_DATE - 0.5).to_string(),
+            "01.01.2000 00:00:00"
+        );
+        assert_eq!(
+            Time::new(
+                J2000_JULIAN_DATE - 0.5 - 1. / 86_400.
+            )
+            .to_string(),
+            "31.12.1999 23:59:59"
+        );
+    }
+
+    #[test]
+    fn round_trips_leap_day() {
+        assert_round_trip(2000, 2, 29);
+        assert_eq!(
+            gregorian_date(
+                days_since_2000(2000, 2, 29) + 1
+            ),
+            (2000, 3, 1)
+        );
+    }
+
+    #[test]
+    fn skips_leap_day_in_century_year() {
+        assert_round_trip(1900, 2, 28);
+        assert_eq!(
+            gregorian_date(
+                days_since_2000(1900, 2, 28) + 1
+            ),
+            (1900, 3, 1)
+        );
+    }
+
+    #[test]
+    fn round_trips_date_before_common_era() {
+        // 15.03.44 до н. э. в астрономическом счёте лет
+        assert_round_trip(-43, 3, 15);
+        assert_round_trip(0, 2, 29);
+        assert_round_trip(-4713, 11, 24);
+    }
+
+    #[test]
+    fn checks_calendar_dates() {
+        assert_eq!(
+            checked_julian_date(2000, 1, 1.5),
+            Some(J2000_JULIAN_DATE)
+        );
+        assert_eq!(
+            checked_julian_date(2024, 2, 29.),
+            Some(julian_date(2024, 2, 29.))
+        );
+        assert_eq!(
+            checked_julian_date(-43, 3, 15.),
+            Some(julian_date(-43, 3, 15.))
+        );
+
+        assert_eq!(checked_julian_date(2024, 2, 30.), None);
+        assert_eq!(checked_julian_date(2023, 2, 29.), None);
+        assert_eq!(checked_julian_date(1900, 2, 29.), None);
+        assert_eq!(checked_julian_date(2024, 4, 31.), None);
+        assert_eq!(checked_julian_date(2024, 13, 1.), None);
+        assert_eq!(checked_julian_date(2024, 0, 1.), None);
+        assert_eq!(checked_julian_date(2024, 1, 0.5), None);
+        assert_eq!(checked_julian_date(2024, 1, 32.), None);
+    }
+}
@@ -1,21 +1,46 @@
-use std::time::Duration;
+use std::{
+    cell::RefCell, process::ExitCode, time::Duration,
+};
 
 use iced::{
-    Element, Fill, Point, Subscription, Theme, application,
+    Element, Fill, Point, Size, Subscription, Theme,
+    application,
     time::every,
     widget::{canvas, stack},
 };
 
-use crate::state::State;
+use comet_movement_core::{
+    config::Config, util::objects::id::ObjectId,
+};
+
+use crate::state::{
+    State, comet_form::CometFormField,
+    view::SpawnProfileOption,
+};
 
 mod objects;
 mod state;
 mod util;
 mod views;
 
-pub fn main() -> iced::Result {
-    application(
-        SolarSystem::new,
+pub fn main() -> ExitCode {
+    // Данные загружаются до открытия окна, чтобы сообщить об ошибке
+    let state = match start() {
+        Ok(state) => state,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    // Состояние забирается при запуске приложения
+    let state = RefCell::new(Some(state));
+
+    let result = application(
+        move || SolarSystem {
+            state: state
+                .take()
+                .expect("Application must boot once"),
+        },
         SolarSystem::update,
         SolarSystem::view,
     )
@@ -23,7 +48,23 @@ pub fn main() -> iced::Result {
     .subscription(SolarSystem::subscription)
     .theme(SolarSystem::theme)
     .antialiasing(true)
-    .run()
+    .run();
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Чтение конфига и запуск симуляции
+fn start() -> Result<State, String> {
+    let config = Config::from_env()
+        .map_err(|error| error.to_string())?;
+
+    State::new(config).map_err(|error| error.to_string())
 }
 
 struct SolarSystem {
@@ -38,24 +79,43 @@ enum Message {
     ScaleInputChange(String),
     LeftButtonPressed(Point),
     LeftButtonReleased,
+    LaunchModeToggle,
+    LaunchPressed {
+        position: Point,
+        canvas_position: Point,
+        bounds: Size,
+    },
+    LaunchDrag {
+        canvas_position: Point,
+        bounds: Size,
+    },
+    LaunchReleased,
     PlayPauseToggle,
     PlanetsViewToggle,
     CometsViewToggle,
-    SatellitesViewToggle(String),
+    SatellitesViewToggle(ObjectId),
     Tick,
     IncreaseSpeed,
     DecreaseSpeed,
+    SpeedChange(f64),
+    TimeDirectionToggle,
+    ChangeIntegrationMethod,
     AddComet,
-    DeleteComet(u8),
+    SpawnProfileSelect(SpawnProfileOption),
+    CometFormToggle,
+    CometFormInput(CometFormField, String),
+    CometFormInitialConditionsToggle,
+    CometFormSubmit,
+    DeleteComet(ObjectId),
     CenterSystem,
+    SystemCenterToggle,
     Reload,
+    SaveSnapshot,
+    OpenSnapshot,
+    RewindTo(u32),
 }
 
 impl SolarSystem {
-    fn new() -> Self {
-        Self { state: State::new() }
-    }
-
     fn update(&mut self, message: Message) {
         match message {
             Message::PositionChange(position) => {
@@ -84,16 +144,53 @@ impl SolarSystem {
                 self.state.decrease_speed()
             }
 
+            Message::SpeedChange(days_per_second) => {
+                self.state.set_speed(days_per_second)
+            }
+
+            Message::TimeDirectionToggle => {
+                self.state.toggle_time_direction()
+            }
+
+            Message::ChangeIntegrationMethod => {
+                self.state.change_integration_method()
+            }
+
             Message::AddComet => self.state.add_comet(),
 
-            Message::DeleteComet(index) => {
-                self.state.delete_comet(index)
+            Message::SpawnProfileSelect(option) => self
+                .state
+                .select_spawn_profile(option.index),
+
+            Message::CometFormToggle => {
+                self.state.comet_form_toggle()
+            }
+
+            Message::CometFormInput(field, value) => self
+                .state
+                .set_comet_form_value(field, value),
+
+            Message::CometFormInitialConditionsToggle => {
+                self.state
+                    .comet_form_initial_conditions_toggle()
+            }
+
+            Message::CometFormSubmit => {
+                self.state.add_comet_from_form()
+            }
+
+            Message::DeleteComet(id) => {
+                self.state.delete_comet(id)
             }
 
             Message::CenterSystem => {
                 self.state.center_system()
             }
 
+            Message::SystemCenterToggle => {
+                self.state.toggle_system_center()
+            }
+
             Message::LeftButtonPressed(position) => {
                 self.state.on_left_button_pressed(position)
             }
@@ -102,8 +199,45 @@ impl SolarSystem {
                 self.state.on_left_button_released()
             }
 
+            Message::LaunchModeToggle => {
+                self.state.toggle_launch_mode()
+            }
+
+            Message::LaunchPressed {
+                position,
+                canvas_position,
+                bounds,
+            } => self.state.on_launch_pressed(
+                position,
+                canvas_position,
+                bounds,
+            ),
+
+            Message::LaunchDrag {
+                canvas_position,
+                bounds,
+            } => self
+                .state
+                .drag_launch(canvas_position, bounds),
+
+            Message::LaunchReleased => {
+                self.state.finish_launch()
+            }
+
             Message::Reload => self.state.reload(),
 
+            Message::SaveSnapshot => {
+                self.state.save_snapshot()
+            }
+
+            Message::RewindTo(index) => {
+                self.state.rewind_to(index as usize)
+            }
+
+            Message::OpenSnapshot => {
+                self.state.open_snapshot()
+            }
+
             Message::PlanetsViewToggle => {
                 self.state.planets_view_toggle()
             }
@@ -112,9 +246,8 @@ impl SolarSystem {
                 self.state.comets_view_toggle()
             }
 
-            Message::SatellitesViewToggle(planet_name) => {
-                self.state
-                    .satellites_view_toggle(planet_name)
+            Message::SatellitesViewToggle(planet_id) => {
+                self.state.satellites_view_toggle(planet_id)
             }
         }
     }
@@ -125,7 +258,9 @@ impl SolarSystem {
 
         let panel = self.control_panel();
 
-        stack![solar_system, panel]
+        let performance = self.performance_hud();
+
+        stack![solar_system, panel, performance]
             .width(Fill)
             .height(Fill)
             .into()
@@ -135,18 +270,13 @@ impl SolarSystem {
         Theme::Moonfly
     }
 
+    /// Опрос рабочего потока о новом состоянии раз в кадр
     fn subscription(&self) -> Subscription<Message> {
-        if self.state.settings.is_running() {
-            every(Duration::from_nanos(
-                self.state
-                    .config
-                    .time_between_ticks_in_nanos()
-                    as u64,
-            ))
-            .map(|_| Message::Tick)
-        } else {
-            Subscription::none()
-        }
+        every(Duration::from_millis(
+            self.state.config.frame_budget_in_millis()
+                as u64,
+        ))
+        .map(|_| Message::Tick)
     }
 
     fn title(&self) -> String {
@@ -1,5 +1,2 @@
-pub mod comet;
-pub mod planet;
-pub mod satellite;
 pub mod stars;
-pub mod sun;
+pub mod view;
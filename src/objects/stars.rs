@@ -1,6 +1,6 @@
 use gset::Getset;
 use iced::Point;
-use rand::{RngExt, rngs::ThreadRng};
+use rand::{RngExt, rngs::ChaCha12Rng};
 
 /// Фоновая звезда
 #[derive(Getset)]
@@ -15,7 +15,7 @@ pub struct Star {
 
 impl Star {
     /// Генерация новой звезды
-    pub fn generate(rng: &mut ThreadRng) -> Self {
+    pub fn generate(rng: &mut ChaCha12Rng) -> Self {
         // Позиция относительно размеров окна
         let relative_point = Point::new(
             rng.random_range(-1.0..=1.0),
@@ -0,0 +1,27 @@
+use comet_movement_core::util::objects::{
+    Object, ObjectMotion,
+};
+
+/// Типаж для отображения информации об объекте
+pub trait ObjectView {
+    /// Путь к картинке
+    fn image_view(&self) -> &str;
+    /// Название
+    fn name_view(&self) -> String;
+    /// Скорость
+    fn velocity_view(&self) -> String;
+}
+
+impl<T: ObjectMotion + Object> ObjectView for T {
+    fn image_view(&self) -> &str {
+        self.image_path()
+    }
+
+    fn name_view(&self) -> String {
+        self.name().to_string()
+    }
+
+    fn velocity_view(&self) -> String {
+        self.movement().velocity().value.to_string()
+    }
+}
@@ -1,8 +1,12 @@
-use std::{
-    cell::{Ref, RefCell},
-    rc::Weak,
-};
+use std::cell::Ref;
 
+use comet_movement_core::{
+    space::SpaceState,
+    util::{
+        geometry::vector::Vector as SpaceVector,
+        objects::{MovingObject, Object},
+    },
+};
 use iced::{
     Color, Point, Rectangle, Renderer, Size, Theme, Vector,
     mouse,
@@ -17,10 +21,12 @@ use tap::TapOptional;
 use crate::{
     Message,
     objects::stars::Star,
-    state::{State, system_position::CursorPinch},
-    util::{
-        geometry::point::translate_point,
-        objects::{MovingObject, Object},
+    state::{
+        State, images::Images, launch::LaunchState,
+        system_position::CursorPinch,
+    },
+    util::geometry::{
+        canvas_color, canvas_point, translate_point,
     },
 };
 
@@ -72,13 +78,13 @@ fn draw_system(
     bounds: Rectangle,
     scale: u32,
     step: u32,
-    all_objects: &[Weak<RefCell<dyn Object>>],
-    moving_objects: &[Weak<RefCell<dyn MovingObject>>],
+    space: &SpaceState,
+    images: &Images,
 ) {
     translate_frame_to_new_center(frame, center_position);
 
     // Отрисовка орбит объектов
-    moving_objects.iter().for_each(|object| {
+    space.moving_objects().iter().for_each(|object| {
         object.upgrade().tap_some(|object_rc| {
             draw_object_orbit(
                 frame,
@@ -92,9 +98,14 @@ fn draw_system(
     });
 
     // Отрисовка объектов
-    all_objects.iter().for_each(|object| {
+    space.all_objects().iter().for_each(|object| {
         object.upgrade().tap_some(|object_rc| {
-            draw_object(frame, scale, object_rc.borrow())
+            draw_object(
+                frame,
+                scale,
+                object_rc.borrow(),
+                images,
+            )
         });
     });
 }
@@ -116,8 +127,9 @@ fn draw_object_orbit(
         );
 
         // Масшабированные позиции объекта с шагом, зависимым от масштаба
-        let mut object_positions =
-            object.trajectory(step, scale as f32);
+        let mut object_positions = object
+            .trajectory(step, scale as f32)
+            .map(canvas_point);
 
         // Проверяем и передвигаем начальную позицию орбиты
         let first_position =
@@ -161,7 +173,9 @@ fn draw_object_orbit(
     frame.stroke(
         &path,
         Stroke {
-            style: Style::Solid(object.trajectory_color()),
+            style: Style::Solid(canvas_color(
+                object.trajectory_color(),
+            )),
             width: 2.,
             ..Stroke::default()
         },
@@ -173,9 +187,11 @@ fn draw_object(
     frame: &mut Frame,
     scale: u32,
     object: Ref<dyn Object>,
+    images: &Images,
 ) {
     let radius = object.scaled_radius(scale);
-    let position = object.scaled_position(scale);
+    let position =
+        canvas_point(object.scaled_position(scale));
 
     // Определение границ изображения
     let bounds = Rectangle::new(
@@ -187,7 +203,93 @@ fn draw_object(
     );
 
     // Отрисовка изображения
-    frame.draw_image(bounds, object.image());
+    frame.draw_image(
+        bounds,
+        images.get(object.image_path()),
+    );
+}
+
+/// Отрисовка стрелки скорости и предсказанного пути запускаемой кометы
+fn draw_launch(
+    frame: &mut Frame,
+    center_position: Point,
+    scale: u32,
+    launch: &LaunchState,
+) {
+    let Some(start) = launch.start() else {
+        return;
+    };
+
+    translate_frame_to_new_center(frame, center_position);
+
+    let scale = scale as f64;
+    let canvas_position = |position: SpaceVector| {
+        Point::new(
+            (position.x / scale) as f32,
+            (position.y / scale) as f32,
+        )
+    };
+
+    // Предсказанный путь
+    let path = Path::new(|builder| {
+        let mut positions = launch
+            .predicted_path()
+            .iter()
+            .map(|position| canvas_position(*position));
+
+        if let Some(first_position) = positions.next() {
+            builder.move_to(first_position);
+            positions.for_each(|position| {
+                builder.line_to(position)
+            });
+        }
+    });
+
+    frame.stroke(
+        &path,
+        Stroke {
+            style: Style::Solid(Color::from_rgba8(
+                255, 255, 255, 0.4,
+            )),
+            width: 1.,
+            ..Stroke::default()
+        },
+    );
+
+    // Стрелка направлена против оттягивания
+    let arrow_start = canvas_position(start);
+    let arrow_end =
+        canvas_position(start + (start - launch.end()));
+    let arrow = arrow_end - arrow_start;
+    let arrow_length = arrow.x.hypot(arrow.y);
+
+    let arrow_path = Path::new(|builder| {
+        builder.move_to(arrow_start);
+        builder.line_to(arrow_end);
+
+        if arrow_length > 0. {
+            // Наконечник стрелки
+            let direction = arrow * (1. / arrow_length);
+            let head_length = arrow_length.min(12.);
+            let back = arrow_end - direction * head_length;
+            let side =
+                Vector::new(-direction.y, direction.x)
+                    * (head_length / 2.);
+
+            builder.move_to(back + side);
+            builder.line_to(arrow_end);
+            builder.line_to(back - side);
+        }
+    });
+
+    frame.stroke(
+        &arrow_path,
+        Stroke {
+            style: Style::Solid(Color::WHITE),
+            width: 2.,
+            ..Stroke::default()
+        },
+    );
 }
 
 impl canvas::Program<Message> for State {
@@ -197,7 +299,7 @@ impl canvas::Program<Message> for State {
         &self,
         _: &mut Self::State,
         event: &Event,
-        _: Rectangle,
+        bounds: Rectangle,
         cursor: Cursor,
     ) -> Option<Action<Message>> {
         match event {
@@ -210,6 +312,23 @@ impl canvas::Program<Message> for State {
                         Message::ScaleChange(*y as i16),
                     )),
 
+                    // Оттягивание запускаемой кометы
+                    mouse::Event::CursorMoved {
+                        ..
+                    } if self.launch.is_dragging() => {
+                        cursor.position_in(bounds).map(
+                            |canvas_position| {
+                                Action::publish(
+                                    Message::LaunchDrag {
+                                        canvas_position,
+                                        bounds: bounds
+                                            .size(),
+                                    },
+                                )
+                            },
+                        )
+                    }
+
                     // Перемещение Солнечной системы
                     mouse::Event::CursorMoved {
                         position,
@@ -225,6 +344,32 @@ impl canvas::Program<Message> for State {
                         ))
                     }
 
+                    // Начало запуска кометы или перемещения Солнечной системы
+                    mouse::Event::ButtonPressed(
+                        Button::Left,
+                    ) if self.launch.is_enabled() => cursor
+                        .position_in(bounds)
+                        .map(|canvas_position| {
+                            Action::publish(
+                                Message::LaunchPressed {
+                                    position: cursor
+                                        .position()
+                                        .unwrap(),
+                                    canvas_position,
+                                    bounds: bounds.size(),
+                                },
+                            )
+                        }),
+
+                    // Запуск оттянутой кометы
+                    mouse::Event::ButtonReleased(
+                        Button::Left,
+                    ) if self.launch.is_dragging() => {
+                        Some(Action::publish(
+                            Message::LaunchReleased,
+                        ))
+                    }
+
                     // Начало перемещения Солнечной системы
                     mouse::Event::ButtonPressed(
                         Button::Left,
@@ -259,25 +404,41 @@ impl canvas::Program<Message> for State {
         let stars = self.cache.stars().draw(
             renderer,
             bounds.size(),
-            |frame| draw_stars(frame, self.space.stars()),
+            |frame| draw_stars(frame, &self.stars),
         );
 
+        let center_position = self.camera_offset();
+
         let system = self.cache.system().draw(
             renderer,
             bounds.size(),
             |frame| {
                 draw_system(
                     frame,
-                    self.system_position.center_position(),
+                    center_position,
                     bounds,
                     self.settings.scale().value(),
                     self.step(),
-                    self.space.all_objects(),
-                    self.space.moving_objects(),
+                    &self.space,
+                    &self.images,
                 )
             },
         );
 
-        vec![stars, system]
+        if !self.launch.is_dragging() {
+            return vec![stars, system];
+        }
+
+        // Запуск меняется с каждым движением мыши и не кешируется
+        let mut launch_frame =
+            Frame::new(renderer, bounds.size());
+        draw_launch(
+            &mut launch_frame,
+            center_position,
+            self.settings.scale().value(),
+            &self.launch,
+        );
+
+        vec![stars, system, launch_frame.into_geometry()]
     }
 }
@@ -0,0 +1,82 @@
+use std::time::Instant;
+
+use crate::state::rate::RateMeter;
+
+/// Секунд в сутках
+const SECONDS_PER_DAY: f64 = 86_400.;
+
+/// Перевод реального времени в шаги интегрирования
+#[derive(Default)]
+pub struct ClockState {
+    /// Начало предыдущего кадра
+    last_frame: Option<Instant>,
+    /// Время симуляции в с, ещё не пройденное шагами
+    pending_seconds: f64,
+    /// Пройденное время симуляции
+    simulated_seconds: RateMeter,
+    /// Сделанные шаги интегрирования
+    steps: RateMeter,
+}
+
+impl ClockState {
+    /// Количество шагов интегрирования, нужное на кадре.
+    ///
+    /// Шаги всегда одного размера, при большей скорости их больше
+    pub fn planned_steps(
+        &mut self,
+        now: Instant,
+        seconds_per_second: f64,
+        step: f64,
+    ) -> u32 {
+        let elapsed =
+            self.last_frame.map_or(0., |last_frame| {
+                now.duration_since(last_frame).as_secs_f64()
+            });
+        self.last_frame = Some(now);
+
+        self.pending_seconds +=
+            seconds_per_second * elapsed;
+
+        let steps = (self.pending_seconds / step).floor();
+        self.pending_seconds -= steps * step;
+
+        steps as u32
+    }
+
+    /// Учёт сделанных на кадре шагов.
+    /// Если шаги не уложились в кадр, отставание не копится
+    pub fn finish_frame(
+        &mut self,
+        now: Instant,
+        planned_steps: u32,
+        steps: u32,
+        step: f64,
+    ) {
+        if steps < planned_steps {
+            self.pending_seconds = 0.;
+        }
+
+        self.simulated_seconds
+            .record(now, steps as f64 * step);
+        self.steps.record(now, steps as f64);
+    }
+}
+
+impl ClockState {
+    /// Действительная скорость в сутках за секунду
+    pub fn effective_days_per_second(&self) -> f64 {
+        self.simulated_seconds.rate() / SECONDS_PER_DAY
+    }
+
+    /// Шагов интегрирования в секунду
+    pub fn steps_per_second(&self) -> f64 {
+        self.steps.rate()
+    }
+}
+
+impl ClockState {
+    /// Сброс при запуске и остановке, чтобы пауза не считалась временем
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
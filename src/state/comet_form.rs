@@ -0,0 +1,347 @@
+use gset::Getset;
+
+use comet_movement_core::{
+    objects::comet::{
+        CometOrbitParameters, CometParameters,
+    },
+    util::{
+        geometry::{point::Point, vector::Vector},
+        physics::{
+            orbit::{
+                PerihelionOrbit, PerihelionPassage,
+                StateVectors,
+            },
+            quantities::{
+                Quantity,
+                parsing::{
+                    QuantityParseError, parse_angle,
+                    parse_distance, parse_duration,
+                    parse_mass, parse_number, parse_speed,
+                },
+                quantity_units::{
+                    Kilograms, Kilometers,
+                    KilometersPerSecond,
+                },
+            },
+            vector::VectorValue,
+        },
+    },
+};
+
+use crate::state::worker::WorkerCommand;
+
+/// Поле формы кометы
+#[derive(Debug, Clone, Copy)]
+pub enum CometFormField {
+    /// Позиция по оси x
+    X,
+    /// Позиция по оси y
+    Y,
+    /// Модуль скорости или её составляющая по оси x
+    FirstVelocity,
+    /// Направление скорости или её составляющая по оси y
+    SecondVelocity,
+    /// Расстояние в перигелии
+    PerihelionDistance,
+    /// Эксцентриситет
+    Eccentricity,
+    /// Направление на перигелий
+    PerihelionDirection,
+    /// Время до прохождения перигелия
+    TimeToPerihelion,
+    /// Масса
+    Mass,
+    /// Радиус
+    Radius,
+}
+
+/// Способ задания начальных условий
+#[derive(Clone, Copy, Default)]
+pub enum InitialConditionsInput {
+    /// Позиция, модуль скорости и угол от оси x к оси y
+    #[default]
+    SpeedAndDirection,
+    /// Позиция и составляющие скорости по осям
+    Components,
+    /// Орбита вокруг Солнца с прямым движением,
+    /// заданная перигелием
+    Perihelion,
+}
+
+/// Форма с начальными условиями новой кометы
+#[derive(Getset, Default)]
+pub struct CometForm {
+    /// Открыта ли форма
+    #[getset(get_copy, vis = "pub")]
+    is_opened: bool,
+    /// Позиция по оси x
+    x: String,
+    /// Позиция по оси y
+    y: String,
+    /// Модуль скорости или её составляющая по оси x
+    first_velocity: String,
+    /// Направление скорости или её составляющая по оси y
+    second_velocity: String,
+    /// Расстояние в перигелии
+    perihelion_distance: String,
+    /// Эксцентриситет
+    eccentricity: String,
+    /// Направление на перигелий
+    perihelion_direction: String,
+    /// Время до прохождения перигелия
+    time_to_perihelion: String,
+    /// Масса
+    mass: String,
+    /// Радиус
+    radius: String,
+    /// Способ задания начальных условий
+    #[getset(get_copy, vis = "pub")]
+    initial_conditions_input: InitialConditionsInput,
+    /// Ошибка последнего добавления
+    error: Option<String>,
+}
+
+impl CometForm {
+    pub fn toggle(&mut self) {
+        self.is_opened = !self.is_opened;
+    }
+
+    pub fn toggle_initial_conditions_input(&mut self) {
+        self.initial_conditions_input = match self
+            .initial_conditions_input
+        {
+            InitialConditionsInput::SpeedAndDirection => {
+                InitialConditionsInput::Components
+            }
+            InitialConditionsInput::Components => {
+                InitialConditionsInput::Perihelion
+            }
+            InitialConditionsInput::Perihelion => {
+                InitialConditionsInput::SpeedAndDirection
+            }
+        }
+    }
+}
+
+impl CometForm {
+    #[inline(always)]
+    pub fn value(&self, field: CometFormField) -> &str {
+        match field {
+            CometFormField::X => &self.x,
+            CometFormField::Y => &self.y,
+            CometFormField::FirstVelocity => {
+                &self.first_velocity
+            }
+            CometFormField::SecondVelocity => {
+                &self.second_velocity
+            }
+            CometFormField::PerihelionDistance => {
+                &self.perihelion_distance
+            }
+            CometFormField::Eccentricity => {
+                &self.eccentricity
+            }
+            CometFormField::PerihelionDirection => {
+                &self.perihelion_direction
+            }
+            CometFormField::TimeToPerihelion => {
+                &self.time_to_perihelion
+            }
+            CometFormField::Mass => &self.mass,
+            CometFormField::Radius => &self.radius,
+        }
+    }
+
+    pub fn set_value(
+        &mut self,
+        field: CometFormField,
+        value: String,
+    ) {
+        *match field {
+            CometFormField::X => &mut self.x,
+            CometFormField::Y => &mut self.y,
+            CometFormField::FirstVelocity => {
+                &mut self.first_velocity
+            }
+            CometFormField::SecondVelocity => {
+                &mut self.second_velocity
+            }
+            CometFormField::PerihelionDistance => {
+                &mut self.perihelion_distance
+            }
+            CometFormField::Eccentricity => {
+                &mut self.eccentricity
+            }
+            CometFormField::PerihelionDirection => {
+                &mut self.perihelion_direction
+            }
+            CometFormField::TimeToPerihelion => {
+                &mut self.time_to_perihelion
+            }
+            CometFormField::Mass => &mut self.mass,
+            CometFormField::Radius => &mut self.radius,
+        } = value;
+    }
+}
+
+impl CometForm {
+    #[inline(always)]
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn set_error(&mut self, error: Option<String>) {
+        self.error = error;
+    }
+}
+
+impl CometForm {
+    /// Команда добавления кометы по введённым значениям
+    pub fn command(&self) -> Result<WorkerCommand, String> {
+        let (mass, radius) = self.mass_and_radius()?;
+
+        if let InitialConditionsInput::Perihelion =
+            self.initial_conditions_input
+        {
+            return Ok(WorkerCommand::AddCometOnOrbit(
+                CometOrbitParameters {
+                    passage: self.perihelion_passage()?,
+                    mass,
+                    radius,
+                },
+            ));
+        }
+
+        Ok(WorkerCommand::AddCometWithParameters(
+            CometParameters {
+                initial_state: self.initial_state()?,
+                mass,
+                radius,
+            },
+        ))
+    }
+
+    /// Начальный вектор состояния из позиции и скорости
+    fn initial_state(
+        &self,
+    ) -> Result<StateVectors, String> {
+        let position = Point {
+            x: field("x", parse_distance(&self.x))?,
+            y: field("y", parse_distance(&self.y))?,
+        };
+
+        let velocity = match self.initial_conditions_input {
+            InitialConditionsInput::Components => {
+                let x = field(
+                    "vx",
+                    parse_speed(&self.first_velocity),
+                )?;
+                let y = field(
+                    "vy",
+                    parse_speed(&self.second_velocity),
+                )?;
+
+                VectorValue::<KilometersPerSecond>::from_vector(
+                    Vector::new(x.value(), y.value()),
+                )
+            }
+            _ => {
+                let speed = field(
+                    "скорость",
+                    parse_speed(&self.first_velocity),
+                )?;
+                let direction = field(
+                    "направление",
+                    parse_angle(&self.second_velocity),
+                )?;
+
+                VectorValue::<KilometersPerSecond>::new(
+                    speed,
+                    Vector::new(
+                        direction.cos(),
+                        direction.sin(),
+                    ),
+                )
+            }
+        };
+
+        Ok(StateVectors { position, velocity })
+    }
+
+    /// Прохождение перигелия на орбите в плоскости эклиптики
+    fn perihelion_passage(
+        &self,
+    ) -> Result<PerihelionPassage, String> {
+        let perihelion_distance = field(
+            "q",
+            parse_distance(&self.perihelion_distance),
+        )?;
+        let eccentricity =
+            field("e", parse_number(&self.eccentricity))?;
+        let perihelion_direction = field(
+            "перигелий",
+            parse_angle(&self.perihelion_direction),
+        )?;
+        let time_to_perihelion = field(
+            "до перигелия",
+            parse_duration(&self.time_to_perihelion),
+        )?;
+
+        if perihelion_distance.value() <= 0. {
+            return Err(
+                "Расстояние в перигелии должно быть положительным"
+                    .into(),
+            );
+        }
+        if eccentricity < 0. {
+            return Err(
+                "Эксцентриситет не может быть отрицательным"
+                    .into(),
+            );
+        }
+
+        Ok(PerihelionPassage {
+            orbit: PerihelionOrbit::new(
+                perihelion_distance,
+                eccentricity,
+                perihelion_direction.to_degrees(),
+                0.,
+                0.,
+            ),
+            time_to_perihelion,
+        })
+    }
+
+    /// Масса и радиус кометы, они положительны
+    fn mass_and_radius(
+        &self,
+    ) -> Result<
+        (Quantity<Kilograms>, Quantity<Kilometers>),
+        String,
+    > {
+        let mass = field("масса", parse_mass(&self.mass))?;
+        let radius =
+            field("радиус", parse_distance(&self.radius))?;
+
+        if mass.value() <= 0. {
+            return Err(
+                "Масса должна быть положительной".into()
+            );
+        }
+        if radius.value() <= 0. {
+            return Err(
+                "Радиус должен быть положительным".into()
+            );
+        }
+
+        Ok((mass, radius))
+    }
+}
+
+/// Ошибка поля с его названием
+fn field<T>(
+    name: &str,
+    value: Result<T, QuantityParseError>,
+) -> Result<T, String> {
+    value.map_err(|error| format!("Поле «{name}»: {error}"))
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use comet_movement_core::space::SpaceState;
+use iced::widget::image;
+use tap::TapOptional;
+
+/// Загруженные изображения объектов по путям к файлам
+#[derive(Default)]
+pub struct Images {
+    handles: HashMap<Box<str>, image::Handle>,
+}
+
+impl Images {
+    /// Загрузка изображений всех объектов и возможных изображений комет
+    pub fn new(space: &SpaceState) -> Self {
+        let mut images = Self::default();
+
+        space.all_objects().iter().for_each(|object| {
+            object.upgrade().tap_some(|object_rc| {
+                images
+                    .insert(object_rc.borrow().image_path())
+            });
+        });
+
+        space
+            .comet_possible_values()
+            .images()
+            .iter()
+            .for_each(|path| images.insert(path));
+
+        images
+    }
+
+    fn insert(&mut self, path: &str) {
+        if !self.handles.contains_key(path) {
+            self.handles.insert(
+                path.into(),
+                image::Handle::from_path(path),
+            );
+        }
+    }
+}
+
+impl Images {
+    /// Изображение по пути к файлу
+    pub fn get(&self, path: &str) -> &image::Handle {
+        self.handles
+            .get(path)
+            .expect("Image of the object must be loaded")
+    }
+}
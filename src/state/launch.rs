@@ -0,0 +1,82 @@
+use gset::Getset;
+
+use comet_movement_core::util::geometry::vector::Vector;
+
+/// Скорость запуска в км/с на пиксель оттягивания
+pub const SPEED_PER_PIXEL: f64 = 0.1;
+/// Шаг предсказания пути в секундах (сутки)
+pub const PREDICTION_STEP_IN_SECONDS: f64 = 86_400.;
+/// Количество шагов предсказания пути (два года)
+pub const PREDICTION_STEPS: usize = 730;
+/// Расстояние в пикселях до объекта, на котором нажатие приходится на него
+pub const OBJECT_PICK_DISTANCE: f64 = 5.;
+
+/// Запуск кометы оттягиванием на холсте
+#[derive(Default, Getset)]
+pub struct LaunchState {
+    /// Включён ли режим запуска
+    #[getset(get_copy, vis = "pub")]
+    is_enabled: bool,
+    /// Точка запуска в км
+    #[getset(get_copy, vis = "pub")]
+    start: Option<Vector<f64>>,
+    /// Текущая точка оттягивания в км
+    #[getset(get_copy, vis = "pub")]
+    end: Vector<f64>,
+    /// Предсказанный путь кометы в км
+    predicted_path: Vec<Vector<f64>>,
+}
+
+impl LaunchState {
+    pub fn toggle(&mut self) {
+        self.is_enabled = !self.is_enabled;
+        self.cancel();
+    }
+
+    /// Начало оттягивания из точки запуска
+    pub fn begin(&mut self, position: Vector<f64>) {
+        self.start = Some(position);
+        self.end = position;
+        self.predicted_path.clear();
+    }
+
+    #[inline(always)]
+    pub fn set_end(&mut self, position: Vector<f64>) {
+        self.end = position;
+    }
+
+    #[inline(always)]
+    pub fn set_predicted_path(
+        &mut self,
+        predicted_path: Vec<Vector<f64>>,
+    ) {
+        self.predicted_path = predicted_path;
+    }
+
+    pub fn cancel(&mut self) {
+        self.start = None;
+        self.predicted_path.clear();
+    }
+}
+
+impl LaunchState {
+    /// Оттягивается ли комета
+    #[inline(always)]
+    pub fn is_dragging(&self) -> bool {
+        self.start.is_some()
+    }
+
+    /// Скорость запуска в км/с, направленная против оттягивания,
+    /// при масштабе в км на пиксель
+    pub fn velocity(&self, scale: u32) -> Vector<f64> {
+        self.start.map_or_else(Vector::default, |start| {
+            (start - self.end)
+                * (SPEED_PER_PIXEL / scale as f64)
+        })
+    }
+
+    #[inline(always)]
+    pub fn predicted_path(&self) -> &[Vector<f64>] {
+        self.predicted_path.as_slice()
+    }
+}
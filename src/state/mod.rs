@@ -1,85 +1,262 @@
-use iced::Point;
+use comet_movement_core::{
+    config::Config,
+    space::{SpaceState, error::SpaceError},
+    util::{
+        geometry::{point::scale_point, vector::Vector},
+        objects::{
+            Object, ObjectScalingSizes, id::ObjectId,
+        },
+        physics::{
+            orbit::StateVectors,
+            quantities::{
+                Quantity, point_without_quantity_units,
+                quantity_units::Seconds,
+            },
+        },
+        time::Time,
+    },
+};
+use std::time::Instant;
+
+use iced::{Point, Size};
+use rand::rngs::ChaCha12Rng;
 
 use crate::{
+    objects::stars::Star,
     state::{
         caches::StateCache,
-        config::Config,
-        redraw::RedrawState,
+        comet_form::CometFormField,
+        images::Images,
+        launch::{
+            LaunchState, OBJECT_PICK_DISTANCE,
+            PREDICTION_STEP_IN_SECONDS, PREDICTION_STEPS,
+        },
+        rate::RateMeter,
+        rewind::RewindTimeline,
         settings::Settings,
-        space::SpaceState,
+        snapshot::{
+            CameraSnapshot, RngState, SNAPSHOT_VERSION,
+            SettingsSnapshot, Snapshot, SnapshotError,
+        },
         system_position::{
             CursorPinch, SolarSystemPositionState,
+            SystemCenter,
         },
         view::ViewState,
+        worker::{
+            InitialState, SimulationSettings,
+            SpacePublication, Worker, WorkerCommand,
+        },
     },
-    util::time::Time,
+    util::geometry::canvas_point,
 };
 
 mod caches;
 mod canvas;
-mod config;
-mod redraw;
-mod settings;
-mod space;
-mod system_position;
-mod view;
+mod clock;
+pub mod comet_form;
+mod images;
+mod launch;
+mod rate;
+mod rewind;
+pub mod settings;
+mod snapshot;
+pub mod system_position;
+pub mod view;
+mod worker;
 
 /// Состояния программы
 pub struct State {
     pub view: ViewState,
     /// Кеши
     pub cache: StateCache,
-    /// Время
+    /// Изображения объектов
+    pub images: Images,
+    /// Фоновые звёзды
+    pub stars: Vec<Star>,
+    /// Время, опубликованное рабочим потоком
     pub time: Time,
     /// Настройки пользователя
     pub settings: Settings,
-    /// Космические объекты
+    /// Копия космических объектов рабочего потока для отрисовки
     pub space: SpaceState,
     /// Позиция Солнечной системы
     pub system_position: SolarSystemPositionState,
+    /// Запуск кометы оттягиванием на холсте
+    pub launch: LaunchState,
     /// Файл конфигурации
     pub config: Config,
-    /// Повторная отрисовка
-    pub redraw: RedrawState,
+    /// Шкала перемотки
+    pub timeline: RewindTimeline,
+    /// Действительная скорость в сутках за секунду
+    pub effective_days_per_second: f64,
+    /// Шагов интегрирования в секунду
+    pub steps_per_second: f64,
+    /// Частота отрисовки
+    pub frame_rate: RateMeter,
+    /// Зерно генератора случайных чисел
+    seed: u64,
+    /// Состояние генератора случайных чисел рабочего потока
+    rng_state: RngState,
+    /// Рабочий поток, в котором идёт симуляция
+    worker: Worker,
 }
 
 impl State {
-    pub fn new() -> State {
-        let config = Config::new();
-
+    pub fn new(
+        config: Config,
+    ) -> Result<State, SpaceError> {
         let settings = Settings::new(
-            config.seconds_per_tick().value() as u16,
+            config.default_days_per_second(),
             config.default_scale(),
+            config.integration_method(),
         );
 
-        let redraw = RedrawState::new(
-            config.ticks_between_redraws(),
-        );
+        // Объекты загружает только рабочий поток,
+        // копия строится по его начальному состоянию
+        let (worker, initial_state) = Worker::spawn(
+            config.clone(),
+            SimulationSettings::from(&settings),
+        )?;
+        let InitialState {
+            space,
+            comet_possible_values,
+            rng_state,
+        } = initial_state;
 
-        let space = SpaceState::new(
-            config.path_to_solar_system_values(),
-            config.path_to_assets(),
-            config.background_stars_count(),
+        let space = SpaceState::from_snapshot(
+            space,
+            comet_possible_values,
             config.maximum_number_of_comets(),
         );
 
         let view = ViewState::new(space.planets());
 
-        State {
+        let images = Images::new(&space);
+
+        // Звёзды продолжают последовательность генератора после объектов
+        let mut rng = rng_state.rng();
+        let stars = Self::generate_stars(
+            config.background_stars_count(),
+            &mut rng,
+        );
+
+        Ok(State {
             view,
             cache: StateCache::default(),
-            time: Time::new(),
+            images,
+            stars,
+            time: Time::new(config.start_julian_date()),
             settings,
             space,
             system_position:
                 SolarSystemPositionState::default(),
+            launch: LaunchState::default(),
+            seed: config.seed(),
+            rng_state,
             config,
-            redraw,
-        }
+            timeline: RewindTimeline::default(),
+            effective_days_per_second: 0.,
+            steps_per_second: 0.,
+            frame_rate: RateMeter::default(),
+            worker,
+        })
+    }
+}
+
+impl State {
+    /// Генерация фоновых звёзд
+    fn generate_stars(
+        stars_count: u16,
+        rng: &mut ChaCha12Rng,
+    ) -> Vec<Star> {
+        (0..stars_count)
+            .map(|_| Star::generate(rng))
+            .collect()
+    }
+}
+
+impl State {
+    #[inline(always)]
+    pub fn seed(&self) -> u64 {
+        self.seed
     }
 }
 
 impl State {
+    /// Масштабированная позиция точки, на которой центрируется камера
+    fn system_center_position(&self) -> Point {
+        let scale = self.settings.scale().value();
+
+        match self.system_position.system_center() {
+            SystemCenter::Sun => canvas_point(
+                self.space
+                    .sun()
+                    .borrow()
+                    .scaled_position(scale),
+            ),
+            SystemCenter::Barycenter => {
+                let point = scale_point(
+                    point_without_quantity_units(
+                        self.space.barycenter(),
+                    ),
+                    scale as f64,
+                );
+                Point::new(point.x as f32, point.y as f32)
+            }
+        }
+    }
+
+    /// Смещение центра координат холста относительно центра окна
+    /// от перемещения мышью с учётом точки, на которой центрируется камера
+    fn camera_offset(&self) -> Point {
+        self.system_position.center_position()
+            - (self.system_center_position()
+                - Point::ORIGIN)
+    }
+
+    /// Позиция в км по точке на холсте
+    fn space_position(
+        &self,
+        position: Point,
+        bounds: Size,
+    ) -> Vector<f64> {
+        let scale = self.settings.scale().value() as f64;
+        let offset = self.camera_offset();
+
+        Vector::new(
+            (position.x - bounds.width / 2. - offset.x)
+                as f64,
+            (position.y - bounds.height / 2. - offset.y)
+                as f64,
+        ) * scale
+    }
+
+    /// Есть ли объект в позиции (в км),
+    /// мелкие объекты выбираются в пределах нескольких пикселей
+    fn is_object_at(&self, position: Vector<f64>) -> bool {
+        let scale = self.settings.scale().value() as f64;
+
+        self.space.all_objects().iter().any(|object| {
+            object.upgrade().is_some_and(|object| {
+                let object = object.borrow();
+                let object_position =
+                    point_without_quantity_units(
+                        object.position(),
+                    );
+                let distance = (position.x
+                    - object_position.x)
+                    .hypot(position.y - object_position.y);
+
+                distance
+                    <= object
+                        .radius()
+                        .value()
+                        .max(OBJECT_PICK_DISTANCE * scale)
+            })
+        })
+    }
+
     fn step(&self) -> u32 {
         self.settings.scale().value()
             / self.config.step_formation()
@@ -88,17 +265,49 @@ impl State {
 }
 
 impl State {
-    /// Обновление при тике
+    /// Обновление по состоянию, опубликованному рабочим потоком
     pub fn update(&mut self) {
-        self.space
-            .move_objects(self.settings.speed().value());
-        self.space.remove_crashed_comets();
-        self.time.add_seconds(
-            self.settings.speed().value().value() as u16,
-        );
-        if self.redraw.redraw_on_tick() {
-            self.cache.clear_system();
+        let now = Instant::now();
+
+        let Some(publication) =
+            self.worker.take_publication()
+        else {
+            // Без нового состояния кадр не перерисовывается
+            self.frame_rate.record(now, 0.);
+            return;
+        };
+
+        match publication.space {
+            SpacePublication::Full(space) => {
+                self.space.restore(space).expect(
+                    "Published space must match the space",
+                )
+            }
+            SpacePublication::Update(update) => {
+                self.space.apply_update(update)
+            }
+        }
+        self.time = publication.time;
+        self.effective_days_per_second =
+            publication.effective_days_per_second;
+        self.timeline = publication.timeline;
+        self.steps_per_second =
+            publication.steps_per_second;
+        self.rng_state = publication.rng_state;
+        if let Some(error) = publication.spawn_error {
+            self.view.set_spawn_status(Some(format!(
+                "Комета не добавлена: {error}"
+            )));
         }
+        self.frame_rate.record(now, 1.);
+        self.cache.clear_system();
+    }
+
+    /// Передача рабочему потоку настроек хода симуляции
+    fn send_settings(&self) {
+        self.worker.send(WorkerCommand::Settings(
+            SimulationSettings::from(&self.settings),
+        ));
     }
 
     /// Изменение масштаба
@@ -168,34 +377,95 @@ impl State {
     /// При нажатии на кнопку запуска симуляции
     pub fn toggle_play_pause(&mut self) {
         self.settings.toggle_running();
+        self.send_settings();
     }
 
     /// Увеличение скорости
     pub fn increase_speed(&mut self) {
         self.settings.speed_mut().set_next();
+        self.send_settings();
     }
 
     /// Уменьшение скорости
     pub fn decrease_speed(&mut self) {
         self.settings.speed_mut().set_previous();
+        self.send_settings();
     }
 
-    /// Добавление новой кометы
+    /// Задание скорости в сутках за секунду
+    pub fn set_speed(&mut self, days_per_second: f64) {
+        self.settings.speed_mut().set(days_per_second);
+        self.send_settings();
+    }
+
+    /// Смена направления течения времени
+    pub fn toggle_time_direction(&mut self) {
+        self.settings.toggle_time_direction();
+        self.send_settings();
+    }
+
+    /// Смена метода численного интегрирования
+    pub fn change_integration_method(&mut self) {
+        self.settings.set_next_integration_method();
+        self.send_settings();
+    }
+
+    /// Добавление новой кометы по выбранному профилю появления
     pub fn add_comet(&mut self) {
-        self.space.add_new_comet();
+        self.view.set_spawn_status(None);
+        self.worker.send(WorkerCommand::AddComet {
+            spawn_profile: self.view.spawn_profile(),
+        });
+    }
+
+    /// Выбор профиля появления новых комет
+    pub fn select_spawn_profile(
+        &mut self,
+        spawn_profile: Option<usize>,
+    ) {
+        self.view.set_spawn_profile(spawn_profile);
+    }
+
+    /// Добавление кометы с параметрами из формы
+    pub fn add_comet_from_form(&mut self) {
+        let form = self.view.comet_form_mut();
+
+        match form.command() {
+            Ok(command) => {
+                form.set_error(None);
+                self.view.set_spawn_status(None);
+                self.worker.send(command);
+            }
+            Err(error) => form.set_error(Some(error)),
+        }
+    }
+
+    /// Изменение поля формы кометы
+    pub fn set_comet_form_value(
+        &mut self,
+        field: CometFormField,
+        value: String,
+    ) {
+        self.view.comet_form_mut().set_value(field, value);
     }
 
     /// Удаление кометы
-    pub fn delete_comet(&mut self, index: u8) {
-        self.space.delete_comet(index);
+    pub fn delete_comet(&mut self, id: ObjectId) {
+        self.worker.send(WorkerCommand::DeleteComet(id));
     }
 
-    /// Центрирование системы на Солнце
+    /// Центрирование системы на Солнце или центре масс
     pub fn center_system(&mut self) {
         self.system_position.center_system_position();
         self.cache.clear_system();
     }
 
+    /// Смена точки, на которой центрируется камера
+    pub fn toggle_system_center(&mut self) {
+        self.system_position.toggle_system_center();
+        self.cache.clear_system();
+    }
+
     /// При нажатии на левую кнопку мыши
     pub fn on_left_button_pressed(
         &mut self,
@@ -215,14 +485,16 @@ impl State {
 
     /// Перезагрука симуляции
     pub fn reload(&mut self) {
-        self.time.restart();
         self.settings.reload(
-            self.config.seconds_per_tick().value() as u16,
+            self.config.default_days_per_second(),
             self.config.default_scale(),
+            self.config.integration_method(),
         );
-        self.space.reload();
         self.system_position.reload();
-        self.redraw.reload();
+        // Снимок мог сменить зерно, перезагрузка возвращает зерно конфига
+        self.seed = self.config.seed();
+        self.worker.send(WorkerCommand::Reload);
+        self.send_settings();
         self.cache.clear_all();
     }
 
@@ -236,11 +508,223 @@ impl State {
         self.view.toggle_comets_view();
     }
 
+    /// Нажатие на кнопку формы кометы
+    pub fn comet_form_toggle(&mut self) {
+        self.view.comet_form_mut().toggle();
+    }
+
+    /// Смена способа задания начальных условий в форме кометы
+    pub fn comet_form_initial_conditions_toggle(&mut self) {
+        self.view
+            .comet_form_mut()
+            .toggle_initial_conditions_input();
+    }
+
     /// Нажатие на меню спутников планеты
     pub fn satellites_view_toggle(
         &mut self,
-        planet_name: String,
+        planet_id: ObjectId,
     ) {
-        self.view.toggle_satellites_view(planet_name);
+        self.view.toggle_satellites_view(planet_id);
+    }
+}
+
+impl State {
+    /// Сохранение снимка симуляции
+    pub fn save_snapshot(&mut self) {
+        let path = self.config.path_to_snapshot();
+
+        let status = match self.snapshot().write(path) {
+            Ok(()) => format!("Сохранено: {path}"),
+            Err(error) => {
+                format!("Ошибка сохранения: {error}")
+            }
+        };
+
+        self.view.set_snapshot_status(status);
+    }
+
+    /// Открытие снимка симуляции
+    pub fn open_snapshot(&mut self) {
+        let path =
+            self.config.path_to_snapshot().to_owned();
+
+        let status = match Snapshot::read(&path)
+            .and_then(|snapshot| self.restore(snapshot))
+        {
+            Ok(()) => format!("Открыто: {path}"),
+            Err(error) => {
+                format!("Ошибка открытия: {error}")
+            }
+        };
+
+        self.view.set_snapshot_status(status);
+    }
+
+    /// Снимок всей симуляции
+    fn snapshot(&self) -> Snapshot {
+        let center_position =
+            self.system_position.center_position();
+
+        Snapshot {
+            version: SNAPSHOT_VERSION,
+            seed: self.seed,
+            rng_state: self.rng_state.clone(),
+            time: self.time.clone(),
+            settings: SettingsSnapshot {
+                speed: *self.settings.speed(),
+                time_direction: self
+                    .settings
+                    .time_direction(),
+                scale: self.settings.scale().value(),
+                integration_method: self
+                    .settings
+                    .integration_method(),
+            },
+            camera: CameraSnapshot {
+                center_position: [
+                    center_position.x,
+                    center_position.y,
+                ],
+                system_center: self
+                    .system_position
+                    .system_center(),
+            },
+            space: self.space.snapshot(true),
+        }
+    }
+
+    /// Восстановление симуляции из снимка,
+    /// генератор случайных чисел продолжает с состояния из снимка.
+    /// Снимок сначала проверяется на копии космических объектов
+    fn restore(
+        &mut self,
+        snapshot: Snapshot,
+    ) -> Result<(), SnapshotError> {
+        let Snapshot {
+            seed,
+            rng_state,
+            time,
+            settings,
+            camera,
+            space,
+            ..
+        } = snapshot;
+
+        // Космические объекты первыми, так как могут не подойти.
+        // Копия обновится полной публикацией рабочего потока
+        self.space.check_snapshot(&space)?;
+
+        self.worker.send(WorkerCommand::Restore {
+            rng_state: rng_state.clone(),
+            time: time.clone(),
+            space: Box::new(space),
+        });
+
+        self.seed = seed;
+        self.rng_state = rng_state;
+        self.time = time;
+        self.settings.restore(
+            settings.speed,
+            settings.time_direction,
+            settings.scale,
+            settings.integration_method,
+        );
+        self.system_position.restore(
+            Point::new(
+                camera.center_position[0],
+                camera.center_position[1],
+            ),
+            camera.system_center,
+        );
+        self.send_settings();
+        self.cache.clear_all();
+
+        Ok(())
+    }
+}
+
+impl State {
+    /// Включение и выключение запуска комет мышью
+    pub fn toggle_launch_mode(&mut self) {
+        self.launch.toggle();
+    }
+
+    /// Нажатие в режиме запуска: на пустом месте начинается оттягивание,
+    /// на объекте — перемещение Солнечной системы
+    pub fn on_launch_pressed(
+        &mut self,
+        position: Point,
+        canvas_position: Point,
+        bounds: Size,
+    ) {
+        let space_position =
+            self.space_position(canvas_position, bounds);
+
+        if self.is_object_at(space_position) {
+            self.on_left_button_pressed(position);
+            return;
+        }
+
+        self.launch.begin(space_position);
+    }
+
+    /// Оттягивание кометы и предсказание её пути
+    pub fn drag_launch(
+        &mut self,
+        canvas_position: Point,
+        bounds: Size,
+    ) {
+        let Some(start) = self.launch.start() else {
+            return;
+        };
+
+        self.launch.set_end(
+            self.space_position(canvas_position, bounds),
+        );
+
+        let initial_state = StateVectors::new(
+            start,
+            self.launch
+                .velocity(self.settings.scale().value()),
+        );
+
+        // Путь в поле тяготения одного Солнца
+        let predicted_path = initial_state
+            .predicted_positions(
+                &self.space.sun_state(),
+                self.space.sun().borrow().mass(),
+                Quantity::new(Seconds::new(
+                    PREDICTION_STEP_IN_SECONDS,
+                )),
+                PREDICTION_STEPS,
+            );
+
+        self.launch.set_predicted_path(predicted_path);
+    }
+
+    /// Отпускание оттянутой кометы
+    pub fn finish_launch(&mut self) {
+        if let Some(start) = self.launch.start() {
+            self.view.set_spawn_status(None);
+            self.worker.send(WorkerCommand::LaunchComet(
+                StateVectors::new(
+                    start,
+                    self.launch.velocity(
+                        self.settings.scale().value(),
+                    ),
+                ),
+            ));
+        }
+
+        self.launch.cancel();
+    }
+}
+
+impl State {
+    /// Перемотка к кадру, симуляция при этом останавливается
+    pub fn rewind_to(&mut self, index: usize) {
+        self.settings.stop();
+        self.worker.send(WorkerCommand::RewindTo(index));
     }
 }
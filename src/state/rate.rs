@@ -0,0 +1,36 @@
+use std::time::{Duration, Instant};
+
+/// Окно измерения частоты
+const MEASUREMENT_WINDOW: Duration = Duration::from_secs(1);
+
+/// Измерение частоты событий в секунду
+#[derive(Default)]
+pub struct RateMeter {
+    /// Начало окна измерения
+    window_start: Option<Instant>,
+    /// Количество событий в окне
+    count: f64,
+    /// Последняя измеренная частота
+    rate: f64,
+}
+
+impl RateMeter {
+    /// Учёт событий, частота обновляется по окончании окна
+    pub fn record(&mut self, now: Instant, count: f64) {
+        let window_start =
+            *self.window_start.get_or_insert(now);
+        self.count += count;
+
+        let window = now.duration_since(window_start);
+        if window >= MEASUREMENT_WINDOW {
+            self.rate = self.count / window.as_secs_f64();
+            self.window_start = Some(now);
+            self.count = 0.;
+        }
+    }
+
+    #[inline(always)]
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::VecDeque;
+
+use comet_movement_core::util::{
+    data::snapshot::SpaceSnapshot, time::Time,
+};
+
+use crate::state::snapshot::RngState;
+
+/// Кадр перемотки
+pub struct RewindFrame {
+    /// Время симуляции
+    pub time: Time,
+    /// Космические объекты без траекторий
+    pub space: SpaceSnapshot,
+    /// Состояние генератора случайных чисел, чтобы новые кометы
+    /// после перемотки повторяли прежние
+    pub rng_state: RngState,
+}
+
+/// Шкала перемотки для отображения
+#[derive(Default)]
+pub struct RewindTimeline {
+    /// Количество кадров
+    pub frames_count: usize,
+    /// Индекс выбранного кадра, либо последнего
+    pub position: usize,
+    /// Время выбранного кадра
+    pub position_time: Option<Time>,
+}
+
+/// Кольцевой буфер кадров для перемотки назад
+pub struct RewindState {
+    /// Кадры от старых к новым
+    frames: VecDeque<RewindFrame>,
+    /// Максимальное количество кадров
+    capacity: usize,
+    /// Тиков между кадрами
+    ticks_between_frames: u32,
+    /// Тиков до нового кадра
+    ticks_until_frame: u32,
+    /// Выбранный кадр, если симуляция перемотана назад
+    position: Option<usize>,
+}
+
+impl RewindState {
+    #[inline(always)]
+    pub fn new(
+        capacity: u16,
+        ticks_between_frames: u32,
+    ) -> Self {
+        Self {
+            frames: VecDeque::with_capacity(
+                capacity as usize,
+            ),
+            capacity: capacity as usize,
+            ticks_between_frames,
+            ticks_until_frame: ticks_between_frames,
+            position: None,
+        }
+    }
+}
+
+impl RewindState {
+    /// Количество кадров
+    #[inline(always)]
+    pub fn frames_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Индекс выбранного кадра, либо последнего
+    pub fn position(&self) -> usize {
+        self.position
+            .unwrap_or(self.frames.len().saturating_sub(1))
+    }
+
+    /// Время кадра по индексу
+    pub fn frame_time(
+        &self,
+        index: usize,
+    ) -> Option<&Time> {
+        self.frames.get(index).map(|frame| &frame.time)
+    }
+
+    /// Шкала перемотки
+    pub fn timeline(&self) -> RewindTimeline {
+        let position = self.position();
+
+        RewindTimeline {
+            frames_count: self.frames_count(),
+            position,
+            position_time: self
+                .frame_time(position)
+                .cloned(),
+        }
+    }
+}
+
+impl RewindState {
+    /// Нужно ли записать кадр на текущем тике
+    pub fn frame_on_tick(&mut self) -> bool {
+        if self.ticks_until_frame > 1 {
+            self.ticks_until_frame -= 1;
+            return false;
+        }
+
+        self.ticks_until_frame = self.ticks_between_frames;
+        true
+    }
+
+    /// Запись нового кадра, старейший вытесняется при заполнении
+    pub fn push(&mut self, frame: RewindFrame) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.frames.len() == self.capacity {
+            self.frames.pop_front();
+        }
+
+        self.frames.push_back(frame);
+    }
+
+    /// Выбор кадра для перемотки
+    pub fn select(
+        &mut self,
+        index: usize,
+    ) -> Option<&RewindFrame> {
+        let frame = self.frames.get(index)?;
+        self.position = Some(index);
+        self.ticks_until_frame = self.ticks_between_frames;
+        Some(frame)
+    }
+
+    /// Продолжение с выбранного кадра:
+    /// более поздние кадры отбрасываются
+    pub fn resume(&mut self) {
+        if let Some(index) = self.position.take() {
+            self.frames.truncate(index + 1);
+        }
+    }
+
+    pub fn reload(&mut self) {
+        self.frames.clear();
+        self.ticks_until_frame = self.ticks_between_frames;
+        self.position = None;
+    }
+}
@@ -1,9 +1,15 @@
 use gset::Getset;
 
-use crate::state::settings::{scale::Scale, speed::Speed};
+use comet_movement_core::util::physics::integrators::IntegrationMethod;
+
+use crate::state::settings::{
+    scale::Scale, speed::Speed,
+    time_direction::TimeDirection,
+};
 
 pub mod scale;
 pub mod speed;
+pub mod time_direction;
 
 /// Настройки пользователя
 #[derive(Getset)]
@@ -12,10 +18,16 @@ pub struct Settings {
     #[getset(get, vis = "pub")]
     #[getset(get_mut, vis = "pub", name = "speed_mut")]
     speed: Speed,
+    /// Направление течения времени
+    #[getset(get_copy, vis = "pub")]
+    time_direction: TimeDirection,
     /// Масштаб
     #[getset(get, vis = "pub")]
     #[getset(get_mut, vis = "pub", name = "scale_mut")]
     scale: Scale,
+    /// Метод численного интегрирования
+    #[getset(get_copy, vis = "pub")]
+    integration_method: IntegrationMethod,
     /// Работает ли симуляция
     #[getset(get_copy, vis = "pub")]
     is_running: bool,
@@ -24,12 +36,15 @@ pub struct Settings {
 impl Settings {
     #[inline]
     pub fn new(
-        base_time_between_ticks: u16,
+        default_days_per_second: f64,
         default_scale: u32,
+        integration_method: IntegrationMethod,
     ) -> Self {
         Self {
-            speed: Speed::new(base_time_between_ticks),
+            speed: Speed::new(default_days_per_second),
+            time_direction: TimeDirection::Forward,
             scale: Scale::new(default_scale),
+            integration_method,
             is_running: false,
         }
     }
@@ -39,16 +54,77 @@ impl Settings {
     pub fn toggle_running(&mut self) {
         self.is_running = !self.is_running;
     }
+
+    pub fn stop(&mut self) {
+        self.is_running = false;
+    }
+}
+
+impl Settings {
+    pub fn toggle_time_direction(&mut self) {
+        self.time_direction.toggle();
+    }
+}
+
+impl Settings {
+    /// Переключение метода интегрирования,
+    /// назад во времени переключаются только обратимые методы
+    pub fn set_next_integration_method(&mut self) {
+        if !self.time_direction.is_backward() {
+            self.integration_method.set_next();
+            return;
+        }
+
+        self.integration_method =
+            self.integration_method.time_reversible();
+        self.integration_method.set_next();
+        while !self.integration_method.is_time_reversible()
+        {
+            self.integration_method.set_next();
+        }
+    }
+
+    /// Метод интегрирования с учётом направления времени:
+    /// назад симуляция идёт только обратимым методом
+    pub fn current_integration_method(
+        &self,
+    ) -> IntegrationMethod {
+        if self.time_direction.is_backward() {
+            self.integration_method.time_reversible()
+        } else {
+            self.integration_method
+        }
+    }
+}
+
+impl Settings {
+    /// Восстановление из снимка, симуляция при этом останавливается
+    pub fn restore(
+        &mut self,
+        speed: Speed,
+        time_direction: TimeDirection,
+        scale: u32,
+        integration_method: IntegrationMethod,
+    ) {
+        self.speed = speed;
+        self.time_direction = time_direction;
+        self.scale.set(scale);
+        self.integration_method = integration_method;
+        self.is_running = false;
+    }
 }
 
 impl Settings {
     pub fn reload(
         &mut self,
-        base_time_between_ticks: u16,
+        default_days_per_second: f64,
         default_scale: u32,
+        integration_method: IntegrationMethod,
     ) {
-        self.speed.reload(base_time_between_ticks);
+        self.speed.reload(default_days_per_second);
+        self.time_direction = TimeDirection::Forward;
         self.scale.reload(default_scale);
+        self.integration_method = integration_method;
         self.is_running = false;
     }
 }
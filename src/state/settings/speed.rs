@@ -1,71 +1,66 @@
 use std::fmt::Display;
 
-use crate::util::physics::quantities::{
-    Quantity, quantity_units::Seconds,
-};
+use serde::{Deserialize, Serialize};
+
+/// Секунд в сутках
+const SECONDS_PER_DAY: f64 = 86_400.;
 
 /// Скорость симуляции
-pub enum Speed {
-    /// Начальный вариант
-    X1(u16),
-    X2(u16),
-    X4(u16),
-    X8(u16),
-    X16(u16),
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct Speed {
+    /// Суток симуляции за секунду реального времени
+    days_per_second: f64,
 }
 
 impl Speed {
-    /// [base_time_between_ticks] -
-    /// Базовый промежуток времени между двуями тиками ([`Time`](crate::util::time::Time))
+    /// Наименьшая скорость
+    pub const MINIMUM_DAYS_PER_SECOND: f64 = 0.01;
+    /// Наибольшая скорость
+    pub const MAXIMUM_DAYS_PER_SECOND: f64 = 1000.;
+
     #[inline(always)]
-    pub fn new(base_time_between_ticks: u16) -> Self {
-        Self::X1(base_time_between_ticks)
+    pub fn new(days_per_second: f64) -> Self {
+        Self {
+            days_per_second: days_per_second.clamp(
+                Self::MINIMUM_DAYS_PER_SECOND,
+                Self::MAXIMUM_DAYS_PER_SECOND,
+            ),
+        }
     }
 }
 
 impl Speed {
+    /// Увеличение скорости вдвое
     pub fn set_next(&mut self) {
-        let next = match self {
-            Speed::X1(time) => Self::X2(*time * 2),
-            Speed::X2(time) => Self::X4(*time * 2),
-            Speed::X4(time) => Self::X8(*time * 2),
-            Speed::X8(time) => Self::X16(*time * 2),
-            Speed::X16(time) => Self::X16(*time),
-        };
-
-        *self = next;
+        *self = Self::new(self.days_per_second * 2.);
     }
 
+    /// Уменьшение скорости вдвое
     pub fn set_previous(&mut self) {
-        let previous = match self {
-            Speed::X1(time) => Self::X1(*time),
-            Speed::X2(time) => Self::X1(*time / 2),
-            Speed::X4(time) => Self::X2(*time / 2),
-            Speed::X8(time) => Self::X4(*time / 2),
-            Speed::X16(time) => Self::X8(*time / 2),
-        };
+        *self = Self::new(self.days_per_second / 2.);
+    }
 
-        *self = previous;
+    pub fn set(&mut self, days_per_second: f64) {
+        *self = Self::new(days_per_second);
     }
 }
 
 impl Speed {
-    pub fn value(&self) -> Quantity<Seconds> {
-        let value = match self {
-            Speed::X1(value) => *value,
-            Speed::X2(value) => *value,
-            Speed::X4(value) => *value,
-            Speed::X8(value) => *value,
-            Speed::X16(value) => *value,
-        };
+    #[inline(always)]
+    pub fn days_per_second(&self) -> f64 {
+        self.days_per_second
+    }
 
-        Quantity::new(Seconds::new(value as f32))
+    /// Секунд симуляции за секунду реального времени
+    #[inline(always)]
+    pub fn seconds_per_second(&self) -> f64 {
+        self.days_per_second * SECONDS_PER_DAY
     }
 }
 
 impl Speed {
-    pub fn reload(&mut self, base_time_between_ticks: u16) {
-        *self = Self::new(base_time_between_ticks)
+    pub fn reload(&mut self, days_per_second: f64) {
+        *self = Self::new(days_per_second)
     }
 }
 
@@ -74,16 +69,6 @@ impl Display for Speed {
         &self,
         fmt: &mut std::fmt::Formatter<'_>,
     ) -> std::fmt::Result {
-        write!(
-            fmt,
-            "x{value}",
-            value = match self {
-                Speed::X1(_) => 1,
-                Speed::X2(_) => 2,
-                Speed::X4(_) => 4,
-                Speed::X8(_) => 8,
-                Speed::X16(_) => 16,
-            }
-        )
+        write!(fmt, "{:.2} сут/с", self.days_per_second)
     }
 }
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Направление течения времени в симуляции
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeDirection {
+    /// Вперёд от начала отсчёта
+    #[default]
+    Forward,
+    /// Назад, в прошлое
+    Backward,
+}
+
+impl TimeDirection {
+    pub fn toggle(&mut self) {
+        *self = match self {
+            Self::Forward => Self::Backward,
+            Self::Backward => Self::Forward,
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_backward(self) -> bool {
+        matches!(self, Self::Backward)
+    }
+}
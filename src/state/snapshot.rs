@@ -0,0 +1,196 @@
+use std::{
+    fmt::{Display, Formatter},
+    fs::{File, read_to_string},
+    io::{self, BufWriter, Write},
+};
+
+use comet_movement_core::util::{
+    data::snapshot::{
+        SnapshotError as SpaceSnapshotError, SpaceSnapshot,
+    },
+    physics::integrators::IntegrationMethod,
+    time::Time,
+};
+use rand::rngs::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{
+    settings::{
+        speed::Speed, time_direction::TimeDirection,
+    },
+    system_position::SystemCenter,
+};
+
+/// Версия формата файла снимка
+pub const SNAPSHOT_VERSION: u32 = 5;
+
+/// Снимок всей симуляции
+#[derive(Serialize, Deserialize)]
+pub struct Snapshot {
+    /// Версия формата
+    pub version: u32,
+    /// Зерно генератора случайных чисел
+    pub seed: u64,
+    /// Состояние генератора случайных чисел
+    pub rng_state: RngState,
+    /// Время симуляции
+    pub time: Time,
+    /// Настройки пользователя
+    pub settings: SettingsSnapshot,
+    /// Камера
+    pub camera: CameraSnapshot,
+    /// Космические объекты
+    pub space: SpaceSnapshot,
+}
+
+/// Снимок настроек пользователя
+#[derive(Serialize, Deserialize)]
+pub struct SettingsSnapshot {
+    /// Скорость симуляции
+    pub speed: Speed,
+    /// Направление течения времени
+    pub time_direction: TimeDirection,
+    /// Масштаб
+    pub scale: u32,
+    /// Метод численного интегрирования
+    pub integration_method: IntegrationMethod,
+}
+
+/// Снимок камеры
+#[derive(Serialize, Deserialize)]
+pub struct CameraSnapshot {
+    /// Смещение Солнечной системы
+    pub center_position: [f32; 2],
+    /// Точка, на которой центрируется камера
+    pub system_center: SystemCenter,
+}
+
+/// Длина сохранённого состояния генератора в байтах
+const RNG_STATE_LENGTH: usize = 49;
+
+/// Состояние генератора случайных чисел,
+/// с него генератор продолжает ту же последовательность
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "Vec<u8>", into = "Vec<u8>")]
+pub struct RngState([u8; RNG_STATE_LENGTH]);
+
+impl RngState {
+    /// Текущее состояние генератора
+    pub fn of(rng: &ChaCha12Rng) -> Self {
+        Self(rng.serialize_state())
+    }
+
+    /// Генератор в сохранённом состоянии
+    pub fn rng(&self) -> ChaCha12Rng {
+        ChaCha12Rng::deserialize_state(&self.0)
+    }
+}
+
+impl TryFrom<Vec<u8>> for RngState {
+    type Error = String;
+
+    fn try_from(
+        bytes: Vec<u8>,
+    ) -> Result<Self, Self::Error> {
+        let length = bytes.len();
+
+        bytes.try_into().map(Self).map_err(|_| {
+            format!(
+                "random generator state must have {RNG_STATE_LENGTH} bytes, got {length}"
+            )
+        })
+    }
+}
+
+impl From<RngState> for Vec<u8> {
+    fn from(state: RngState) -> Self {
+        state.0.into()
+    }
+}
+
+/// Версия формата для проверки до чтения всего снимка
+#[derive(Deserialize)]
+struct SnapshotVersion {
+    version: u32,
+}
+
+impl Snapshot {
+    /// Запись снимка в JSON файл
+    pub fn write(
+        &self,
+        path: &str,
+    ) -> Result<(), SnapshotError> {
+        let mut writer =
+            BufWriter::new(File::create(path)?);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Чтение снимка из JSON файла
+    pub fn read(path: &str) -> Result<Self, SnapshotError> {
+        let json = read_to_string(path)?;
+
+        let SnapshotVersion { version } =
+            serde_json::from_str(&json)?;
+        if version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(
+                version,
+            ));
+        }
+
+        Ok(serde_json::from_str(&json)?)
+    }
+}
+
+/// Ошибка сохранения или открытия снимка
+pub enum SnapshotError {
+    /// Ошибка чтения или записи файла
+    Io(io::Error),
+    /// Некорректный JSON
+    Json(serde_json::Error),
+    /// Неподдерживаемая версия формата
+    UnsupportedVersion(u32),
+    /// Снимок не подходит к текущей Солнечной системе
+    Space(SpaceSnapshotError),
+}
+
+impl From<io::Error> for SnapshotError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for SnapshotError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+impl From<SpaceSnapshotError> for SnapshotError {
+    fn from(error: SpaceSnapshotError) -> Self {
+        Self::Space(error)
+    }
+}
+
+impl Display for SnapshotError {
+    fn fmt(
+        &self,
+        fmt: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => {
+                write!(fmt, "ошибка файла: {error}")
+            }
+            Self::Json(error) => {
+                write!(fmt, "некорректный снимок: {error}")
+            }
+            Self::UnsupportedVersion(version) => write!(
+                fmt,
+                "неподдерживаемая версия снимка {version}, \
+                 ожидается {SNAPSHOT_VERSION}"
+            ),
+            Self::Space(error) => write!(fmt, "{error}"),
+        }
+    }
+}
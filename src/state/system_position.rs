@@ -1,5 +1,6 @@
 use gset::Getset;
 use iced::Point;
+use serde::{Deserialize, Serialize};
 
 /// Состояние позиции Солнечной системы
 #[derive(Default, Getset)]
@@ -11,6 +12,9 @@ pub struct SolarSystemPositionState {
     last_cursor_position: Option<Point>,
     #[getset(get, vis = "pub")]
     pinch: CursorPinch,
+    /// Точка, на которой центрируется камера
+    #[getset(get_copy, vis = "pub")]
+    system_center: SystemCenter,
 }
 
 impl SolarSystemPositionState {
@@ -50,6 +54,26 @@ impl SolarSystemPositionState {
     pub fn center_system_position(&mut self) {
         self.center_position = Point::ORIGIN;
     }
+
+    pub fn toggle_system_center(&mut self) {
+        self.system_center = match self.system_center {
+            SystemCenter::Sun => SystemCenter::Barycenter,
+            SystemCenter::Barycenter => SystemCenter::Sun,
+        };
+    }
+}
+
+impl SolarSystemPositionState {
+    /// Восстановление позиции из снимка
+    pub fn restore(
+        &mut self,
+        center_position: Point,
+        system_center: SystemCenter,
+    ) {
+        self.reload();
+        self.center_position = center_position;
+        self.system_center = system_center;
+    }
 }
 
 impl SolarSystemPositionState {
@@ -60,6 +84,17 @@ impl SolarSystemPositionState {
     }
 }
 
+/// Точка центрирования камеры
+#[derive(Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SystemCenter {
+    /// Солнце
+    #[default]
+    Sun,
+    /// Центр масс системы
+    Barycenter,
+}
+
 /// Состояние нажатия на левую кнопку мыши
 #[derive(Default)]
 pub enum CursorPinch {
@@ -1,17 +1,25 @@
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    rc::Rc,
+};
 
 use gset::Getset;
 use iced::Color;
 
-use crate::{
-    objects::planet::Planet, util::objects::Object,
+use comet_movement_core::{
+    objects::planet::Planet,
+    util::objects::{Object, id::ObjectId},
 };
 
+use crate::state::comet_form::CometForm;
+
 /// Состояние UI
 #[derive(Getset)]
 pub struct ViewState {
     /// Открыты ли меню спутников планет
-    satellites_views_opened: HashMap<String, bool>,
+    satellites_views_opened: HashMap<ObjectId, bool>,
     /// Открыта ли меню планет
     #[getset(get_copy, vis = "pub")]
     planets_views_opened: bool,
@@ -21,16 +29,31 @@ pub struct ViewState {
     /// Цвет текста ввода масштаба
     #[getset(get_copy, vis = "pub")]
     scale_input_color: Color,
+    /// Результат последнего сохранения или открытия снимка
+    snapshot_status: Option<String>,
+    /// Выбранный профиль появления новых комет,
+    /// без профиля кометы появляются случайно
+    #[getset(get_copy, vis = "pub")]
+    #[getset(set, vis = "pub")]
+    spawn_profile: Option<usize>,
+    /// Форма новой кометы
+    #[getset(get, vis = "pub")]
+    #[getset(
+        get_mut,
+        vis = "pub",
+        name = "comet_form_mut"
+    )]
+    comet_form: CometForm,
+    /// Причина, по которой не добавлена последняя комета
+    spawn_status: Option<String>,
 }
 
 impl ViewState {
     pub fn new(planets: &[Rc<RefCell<Planet>>]) -> Self {
         let planets_satellites_view = planets
             .iter()
-            .map(|planet| {
-                (planet.borrow().name().to_string(), false)
-            })
-            .collect::<HashMap<String, bool>>();
+            .map(|planet| (planet.borrow().id(), false))
+            .collect::<HashMap<ObjectId, bool>>();
 
         Self {
             satellites_views_opened:
@@ -38,6 +61,10 @@ impl ViewState {
             planets_views_opened: true,
             comets_views_opened: true,
             scale_input_color: Color::WHITE,
+            snapshot_status: None,
+            spawn_profile: None,
+            comet_form: CometForm::default(),
+            spawn_status: None,
         }
     }
 }
@@ -46,17 +73,17 @@ impl ViewState {
     #[inline(always)]
     pub fn is_satellites_opened(
         &self,
-        planet_name: String,
+        planet_id: ObjectId,
     ) -> bool {
-        self.satellites_views_opened[&planet_name]
+        self.satellites_views_opened[&planet_id]
     }
 
     pub fn toggle_satellites_view(
         &mut self,
-        planet_name: String,
+        planet_id: ObjectId,
     ) {
         self.satellites_views_opened
-            .entry(planet_name)
+            .entry(planet_id)
             .and_modify(|opened| *opened = !*opened);
     }
 }
@@ -84,3 +111,46 @@ impl ViewState {
         self.scale_input_color = Color::WHITE;
     }
 }
+
+impl ViewState {
+    #[inline(always)]
+    pub fn snapshot_status(&self) -> Option<&str> {
+        self.snapshot_status.as_deref()
+    }
+
+    pub fn set_snapshot_status(&mut self, status: String) {
+        self.snapshot_status = Some(status);
+    }
+}
+
+impl ViewState {
+    #[inline(always)]
+    pub fn spawn_status(&self) -> Option<&str> {
+        self.spawn_status.as_deref()
+    }
+
+    pub fn set_spawn_status(
+        &mut self,
+        status: Option<String>,
+    ) {
+        self.spawn_status = status;
+    }
+}
+
+/// Вариант выбора профиля появления комет
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpawnProfileOption {
+    /// Индекс профиля, без него кометы появляются случайно
+    pub index: Option<usize>,
+    /// Название
+    pub name: Box<str>,
+}
+
+impl Display for SpawnProfileOption {
+    fn fmt(
+        &self,
+        fmt: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(fmt, "{}", self.name)
+    }
+}
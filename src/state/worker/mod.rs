@@ -0,0 +1,224 @@
+use std::{
+    sync::{
+        Arc, Mutex,
+        mpsc::{Sender, channel},
+    },
+    thread,
+};
+
+use comet_movement_core::{
+    config::Config,
+    objects::comet::{
+        CometOrbitParameters, CometParameters,
+        CometPossibleValues,
+    },
+    space::{error::SpaceError, spawn::SpawnError},
+    util::{
+        data::{
+            snapshot::SpaceSnapshot, update::SpaceUpdate,
+        },
+        objects::id::ObjectId,
+        physics::{
+            integrators::{IntegrationMethod, Integrator},
+            orbit::StateVectors,
+            quantities::{
+                Quantity, quantity_units::Seconds,
+            },
+        },
+        time::Time,
+    },
+};
+
+use crate::state::{
+    rewind::RewindTimeline,
+    settings::{
+        Settings, speed::Speed,
+        time_direction::TimeDirection,
+    },
+    snapshot::RngState,
+    worker::simulation::Simulation,
+};
+
+mod simulation;
+
+/// Последнее опубликованное состояние, общее для потоков
+type PublicationSlot = Arc<Mutex<Option<Publication>>>;
+
+/// Команда рабочему потоку
+pub enum WorkerCommand {
+    /// Новые настройки хода симуляции
+    Settings(SimulationSettings),
+    /// Добавление новой кометы по профилю появления
+    AddComet { spawn_profile: Option<usize> },
+    /// Добавление кометы с заданными параметрами
+    AddCometWithParameters(CometParameters),
+    /// Добавление кометы с заданными параметрами орбиты
+    AddCometOnOrbit(CometOrbitParameters),
+    /// Запуск новой кометы с заданным начальным вектором состояния
+    LaunchComet(StateVectors),
+    /// Удаление кометы
+    DeleteComet(ObjectId),
+    /// Перезагрузка симуляции с зерном генератора из конфига
+    Reload,
+    /// Восстановление из проверенного снимка
+    Restore {
+        rng_state: RngState,
+        time: Time,
+        space: Box<SpaceSnapshot>,
+    },
+    /// Перемотка к кадру, симуляция при этом останавливается
+    RewindTo(usize),
+}
+
+/// Настройки, влияющие на ход симуляции
+#[derive(Clone, Copy)]
+pub struct SimulationSettings {
+    /// Работает ли симуляция
+    pub is_running: bool,
+    /// Скорость симуляции
+    pub speed: Speed,
+    /// Направление течения времени
+    pub time_direction: TimeDirection,
+    /// Метод интегрирования с учётом направления времени
+    pub integration_method: IntegrationMethod,
+}
+
+impl From<&Settings> for SimulationSettings {
+    fn from(settings: &Settings) -> Self {
+        Self {
+            is_running: settings.is_running(),
+            speed: *settings.speed(),
+            time_direction: settings.time_direction(),
+            integration_method: settings
+                .current_integration_method(),
+        }
+    }
+}
+
+impl SimulationSettings {
+    /// Шаг интегрирования с учётом направления времени
+    pub fn directed_step(
+        &self,
+        step: Quantity<Seconds>,
+    ) -> Quantity<Seconds> {
+        let step = step.value();
+
+        Quantity::new(Seconds::new(
+            if self.time_direction.is_backward() {
+                -step
+            } else {
+                step
+            },
+        ))
+    }
+
+    #[inline(always)]
+    pub fn integrator(&self) -> &'static dyn Integrator {
+        self.integration_method.integrator()
+    }
+}
+
+/// Состояние симуляции, опубликованное рабочим потоком
+pub struct Publication {
+    /// Время симуляции
+    pub time: Time,
+    /// Космические объекты
+    pub space: SpacePublication,
+    /// Действительная скорость в сутках за секунду
+    pub effective_days_per_second: f64,
+    /// Шагов интегрирования в секунду
+    pub steps_per_second: f64,
+    /// Шкала перемотки
+    pub timeline: RewindTimeline,
+    /// Ошибка последнего добавления кометы, если оно не удалось
+    pub spawn_error: Option<SpawnError>,
+    /// Состояние генератора случайных чисел
+    pub rng_state: RngState,
+}
+
+/// Начальное состояние рабочего потока, по которому строится копия
+pub struct InitialState {
+    /// Космические объекты
+    pub space: SpaceSnapshot,
+    /// Возможные значения комет
+    pub comet_possible_values: CometPossibleValues,
+    /// Состояние генератора случайных чисел
+    pub rng_state: RngState,
+}
+
+/// Опубликованные космические объекты
+pub enum SpacePublication {
+    /// Полный снимок, по которому копия строится заново
+    Full(SpaceSnapshot),
+    /// Изменение с прошлой публикации, применяемое к копии
+    Update(SpaceUpdate),
+}
+
+/// Рабочий поток, в котором идёт симуляция
+pub struct Worker {
+    /// Канал команд
+    commands: Sender<WorkerCommand>,
+    /// Последнее опубликованное состояние
+    publication: PublicationSlot,
+}
+
+impl Worker {
+    /// Запуск рабочего потока с ожиданием его начального состояния,
+    /// поток завершается вместе с владельцем канала команд
+    pub fn spawn(
+        config: Config,
+        settings: SimulationSettings,
+    ) -> Result<(Self, InitialState), SpaceError> {
+        let (commands, receiver) = channel();
+        let (initial_sender, initial_receiver) = channel();
+        let publication = PublicationSlot::default();
+
+        let simulation_publication = publication.clone();
+        thread::Builder::new()
+            .name("simulation".into())
+            .spawn(move || {
+                let mut simulation = match Simulation::new(
+                    config,
+                    settings,
+                    simulation_publication,
+                ) {
+                    Ok(simulation) => simulation,
+                    Err(error) => {
+                        let _ =
+                            initial_sender.send(Err(error));
+                        return;
+                    }
+                };
+
+                // Интерфейс ждёт начальное состояние,
+                // без него он не запускается
+                let _ = initial_sender
+                    .send(Ok(simulation.initial_state()));
+
+                simulation.run(receiver)
+            })
+            .expect("Simulation thread must start");
+
+        let initial_state = initial_receiver
+            .recv()
+            .expect("Simulation thread must start")?;
+
+        Ok((Self { commands, publication }, initial_state))
+    }
+}
+
+impl Worker {
+    pub fn send(&self, command: WorkerCommand) {
+        self.commands
+            .send(command)
+            .expect("Simulation thread must be running");
+    }
+
+    /// Новое опубликованное состояние, если оно появилось
+    pub fn take_publication(&self) -> Option<Publication> {
+        self.publication
+            .lock()
+            .expect("Simulation thread must not panic")
+            .take()
+    }
+}
@@ -0,0 +1,422 @@
+use std::{
+    sync::mpsc::{
+        Receiver, RecvTimeoutError, TryRecvError,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use comet_movement_core::{
+    config::Config,
+    space::{
+        SpaceState, error::SpaceError, spawn::SpawnError,
+    },
+    util::{data::update::PublishedPositions, time::Time},
+};
+use rand::{SeedableRng, rngs::ChaCha12Rng};
+
+use crate::state::{
+    clock::ClockState,
+    rewind::{RewindFrame, RewindState},
+    snapshot::RngState,
+    worker::{
+        InitialState, Publication, PublicationSlot,
+        SimulationSettings, SpacePublication,
+        WorkerCommand,
+    },
+};
+
+/// Симуляция, идущая в рабочем потоке
+pub struct Simulation {
+    /// Файл конфигурации
+    config: Config,
+    /// Космические объекты
+    space: SpaceState,
+    /// Время
+    time: Time,
+    /// Генератор случайных чисел
+    rng: ChaCha12Rng,
+    /// Настройки хода симуляции
+    settings: SimulationSettings,
+    /// Перевод реального времени в шаги интегрирования
+    clock: ClockState,
+    /// Время на один кадр
+    frame_budget: Duration,
+    /// Кадры для перемотки
+    rewind: RewindState,
+    /// Последнее опубликованное состояние
+    publication: PublicationSlot,
+    /// Нужно ли опубликовать состояние
+    is_publication_needed: bool,
+    /// Нужно ли опубликовать объекты целиком, а не изменение
+    is_full_publication_needed: bool,
+    /// Позиции траекторий, уже переданные копии
+    published_positions: PublishedPositions,
+    /// Ошибка добавления кометы, ещё не опубликованная
+    spawn_error: Option<SpawnError>,
+}
+
+impl Simulation {
+    pub fn new(
+        config: Config,
+        settings: SimulationSettings,
+        publication: PublicationSlot,
+    ) -> Result<Self, SpaceError> {
+        let mut rng =
+            ChaCha12Rng::seed_from_u64(config.seed());
+
+        let space = SpaceState::new(&config, &mut rng)?;
+
+        let rewind = RewindState::new(
+            config.rewind_capacity(),
+            config.ticks_between_rewind_frames(),
+        );
+
+        let mut simulation = Self {
+            space,
+            time: Time::new(config.start_julian_date()),
+            rng,
+            settings,
+            clock: ClockState::default(),
+            frame_budget: Duration::from_millis(
+                config.frame_budget_in_millis() as u64,
+            ),
+            rewind,
+            publication,
+            is_publication_needed: true,
+            is_full_publication_needed: true,
+            published_positions: PublishedPositions::new(),
+            spawn_error: None,
+            config,
+        };
+
+        simulation.record_rewind_frame();
+
+        Ok(simulation)
+    }
+}
+
+impl Simulation {
+    /// Начальное состояние для построения копии в интерфейсе,
+    /// после него публикуются только изменения
+    pub fn initial_state(&mut self) -> InitialState {
+        self.space
+            .mark_published(&mut self.published_positions);
+        self.is_full_publication_needed = false;
+
+        InitialState {
+            space: self.space.snapshot(true),
+            comet_possible_values: self
+                .space
+                .comet_possible_values()
+                .clone(),
+            rng_state: RngState::of(&self.rng),
+        }
+    }
+}
+
+impl Simulation {
+    /// Цикл рабочего потока до закрытия канала команд
+    pub fn run(
+        mut self,
+        commands: Receiver<WorkerCommand>,
+    ) {
+        loop {
+            // На паузе поток спит до новой команды,
+            // а с неотправленной публикацией — не дольше кадра
+            if !self.settings.is_running {
+                if self.is_publication_needed {
+                    match commands
+                        .recv_timeout(self.frame_budget)
+                    {
+                        Ok(command) => {
+                            self.execute(command)
+                        }
+                        Err(RecvTimeoutError::Timeout) => {}
+                        Err(
+                            RecvTimeoutError::Disconnected,
+                        ) => {
+                            return;
+                        }
+                    }
+                } else {
+                    match commands.recv() {
+                        Ok(command) => {
+                            self.execute(command)
+                        }
+                        Err(_) => return,
+                    }
+                }
+            }
+
+            loop {
+                match commands.try_recv() {
+                    Ok(command) => self.execute(command),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        return;
+                    }
+                }
+            }
+
+            let frame_start = Instant::now();
+
+            if self.settings.is_running {
+                self.step(frame_start);
+            }
+
+            // Отрисовка один раз за кадр
+            if self.is_publication_needed {
+                self.publish();
+            }
+
+            // Остаток кадра поток спит
+            if let Some(rest) = (frame_start
+                + self.frame_budget)
+                .checked_duration_since(Instant::now())
+            {
+                thread::sleep(rest);
+            }
+        }
+    }
+
+    /// Столько шагов интегрирования постоянного размера,
+    /// сколько требует скорость и сколько успевает за кадр
+    fn step(&mut self, frame_start: Instant) {
+        let step_seconds =
+            self.config.seconds_per_tick().value();
+        let planned_steps = self.clock.planned_steps(
+            frame_start,
+            self.settings.speed.seconds_per_second(),
+            step_seconds,
+        );
+
+        if planned_steps == 0 {
+            return;
+        }
+
+        self.rewind.resume();
+
+        let step = self
+            .settings
+            .directed_step(self.config.seconds_per_tick());
+        let deadline = frame_start + self.frame_budget;
+        let mut steps = 0;
+
+        while steps < planned_steps
+            && Instant::now() < deadline
+        {
+            self.space.move_objects(
+                step,
+                self.settings.integrator(),
+                self.config.step_control(),
+            );
+            self.space.remove_crashed_comets();
+            self.time.add_seconds(step);
+            if self.rewind.frame_on_tick() {
+                self.record_rewind_frame();
+            }
+            steps += 1;
+        }
+
+        self.clock.finish_frame(
+            Instant::now(),
+            planned_steps,
+            steps,
+            step_seconds,
+        );
+        self.is_publication_needed = true;
+    }
+
+    /// Публикация текущего состояния
+    fn publish(&mut self) {
+        let mut publication = self
+            .publication
+            .lock()
+            .expect("Interface thread must not panic");
+
+        // Изменение нельзя записать поверх не взятой публикации,
+        // иначе копия его пропустит, поэтому оно ждёт следующего кадра
+        if publication.is_some()
+            && !self.is_full_publication_needed
+        {
+            return;
+        }
+
+        // Ошибка из ещё не взятой публикации не теряется
+        let spawn_error =
+            self.spawn_error.take().or_else(|| {
+                publication.take().and_then(|publication| {
+                    publication.spawn_error
+                })
+            });
+
+        let space = if self.is_full_publication_needed {
+            self.space.mark_published(
+                &mut self.published_positions,
+            );
+            SpacePublication::Full(
+                self.space.snapshot(true),
+            )
+        } else {
+            SpacePublication::Update(
+                self.space.update_since(
+                    &mut self.published_positions,
+                ),
+            )
+        };
+
+        *publication = Some(Publication {
+            time: self.time.clone(),
+            space,
+            effective_days_per_second: self
+                .clock
+                .effective_days_per_second(),
+            steps_per_second: self.clock.steps_per_second(),
+            timeline: self.rewind.timeline(),
+            spawn_error,
+            rng_state: RngState::of(&self.rng),
+        });
+        drop(publication);
+
+        self.is_publication_needed = false;
+        self.is_full_publication_needed = false;
+    }
+}
+
+impl Simulation {
+    /// Выполнение команды
+    fn execute(&mut self, command: WorkerCommand) {
+        match command {
+            WorkerCommand::Settings(settings) => {
+                if settings.is_running
+                    != self.settings.is_running
+                {
+                    self.clock.reset();
+                }
+                self.settings = settings;
+                return;
+            }
+
+            WorkerCommand::AddComet { spawn_profile } => {
+                self.rewind.resume();
+                self.spawn_error = match spawn_profile {
+                    Some(index) => self
+                        .space
+                        .add_new_comet_from_profile(
+                            index,
+                            &mut self.rng,
+                        ),
+                    None => self
+                        .space
+                        .add_new_comet(&mut self.rng),
+                }
+                .err();
+            }
+
+            WorkerCommand::AddCometWithParameters(
+                parameters,
+            ) => {
+                self.rewind.resume();
+                self.spawn_error = self
+                    .space
+                    .add_comet_with_parameters(
+                        parameters,
+                        &mut self.rng,
+                    )
+                    .err();
+            }
+
+            WorkerCommand::AddCometOnOrbit(parameters) => {
+                self.rewind.resume();
+                self.spawn_error = self
+                    .space
+                    .add_comet_on_orbit(
+                        parameters,
+                        &mut self.rng,
+                    )
+                    .err();
+            }
+
+            WorkerCommand::LaunchComet(initial_state) => {
+                self.rewind.resume();
+                self.spawn_error = self
+                    .space
+                    .add_new_comet_with_state(
+                        initial_state,
+                        &mut self.rng,
+                    )
+                    .err();
+            }
+
+            WorkerCommand::DeleteComet(id) => {
+                self.rewind.resume();
+                self.space.delete_comet(id);
+            }
+
+            WorkerCommand::Reload => {
+                self.time.restart(
+                    self.config.start_julian_date(),
+                );
+                // Генератор начинает заново с зерна из конфига,
+                // чтобы перезагрузка повторялась
+                self.rng = ChaCha12Rng::seed_from_u64(
+                    self.config.seed(),
+                );
+                self.space.reload(&mut self.rng);
+                self.restart_records();
+                self.is_full_publication_needed = true;
+            }
+
+            WorkerCommand::Restore {
+                rng_state,
+                time,
+                space,
+            } => {
+                self.space.restore(*space).expect(
+                    "Snapshot must be checked before restoring",
+                );
+                self.rng = rng_state.rng();
+                self.time = time;
+                self.restart_records();
+                self.is_full_publication_needed = true;
+            }
+
+            WorkerCommand::RewindTo(index) => {
+                let Some(frame) = self.rewind.select(index)
+                else {
+                    return;
+                };
+
+                self.space
+                    .restore(frame.space.clone())
+                    .expect(
+                        "Rewind frame must match the space",
+                    );
+                self.time = frame.time.clone();
+                self.rng = frame.rng_state.rng();
+                self.settings.is_running = false;
+                self.clock.reset();
+                self.is_full_publication_needed = true;
+            }
+        }
+
+        self.is_publication_needed = true;
+    }
+
+    /// Начало записи кадров и отсчёта времени заново
+    fn restart_records(&mut self) {
+        self.clock.reset();
+        self.rewind.reload();
+        self.record_rewind_frame();
+    }
+
+    /// Запись кадра перемотки с текущим состоянием
+    fn record_rewind_frame(&mut self) {
+        self.rewind.push(RewindFrame {
+            time: self.time.clone(),
+            space: self.space.snapshot(false),
+            rng_state: RngState::of(&self.rng),
+        });
+    }
+}
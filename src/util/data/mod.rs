@@ -1,2 +0,0 @@
-pub mod config_data;
-pub mod solar_system_data;
@@ -0,0 +1,37 @@
+use comet_movement_core::util::{
+    color::Color, geometry::point::Point,
+};
+use iced::Point as CanvasPoint;
+
+/// Перенос точки в другую систему координат
+pub fn translate_point(
+    point_in_previous_system: CanvasPoint,
+    previous_center_point: CanvasPoint,
+    new_center_point: CanvasPoint,
+) -> CanvasPoint {
+    let delta_x =
+        previous_center_point.x - new_center_point.x;
+    let delta_y =
+        previous_center_point.y - new_center_point.y;
+
+    CanvasPoint::new(
+        point_in_previous_system.x + delta_x,
+        point_in_previous_system.y + delta_y,
+    )
+}
+
+/// Точка холста из масштабированной точки симуляции
+#[inline(always)]
+pub fn canvas_point(
+    Point { x, y }: Point<f32>,
+) -> CanvasPoint {
+    CanvasPoint::new(x, y)
+}
+
+/// Полупрозрачный цвет холста из цвета траектории
+#[inline(always)]
+pub fn canvas_color(
+    Color { r, g, b }: Color,
+) -> iced::Color {
+    iced::Color::from_rgba8(r, g, b, 0.8)
+}
@@ -1,5 +1 @@
-pub mod data;
 pub mod geometry;
-pub mod objects;
-pub mod physics;
-pub mod time;
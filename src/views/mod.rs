@@ -2,11 +2,18 @@ use iced::{
     Background, Border, Color, Element, Fill,
     alignment::{Horizontal, Vertical},
     widget::{
-        button, column, container, row, text, text_input,
+        Space, button, column, container, row, slider,
+        text, text_input,
     },
 };
 
-use crate::{Message, SolarSystem};
+use crate::{
+    Message, SolarSystem,
+    state::{
+        settings::speed::Speed,
+        system_position::SystemCenter,
+    },
+};
 
 mod objects;
 
@@ -20,8 +27,15 @@ impl SolarSystem {
                 self.speed_panel(),
             ]
             .spacing(4),
+            self.speed_rate_panel(),
             self.reload_and_center_panel(),
+            self.system_center_panel(),
+            self.integration_method_panel(),
+            self.launch_panel(),
             self.scale_panel(),
+            self.seed_panel(),
+            self.snapshot_panel(),
+            self.rewind_panel(),
         ]
         .align_x(Horizontal::Right)
         .spacing(4);
@@ -37,6 +51,29 @@ impl SolarSystem {
     }
 }
 
+impl SolarSystem {
+    /// Измеренная производительность в левом верхнем углу
+    pub fn performance_hud(&self) -> Element<'_, Message> {
+        container(
+            text!(
+                "Шагов/с: {steps:.0}\nКадров/с: {frames:.0}",
+                steps = self.state.steps_per_second,
+                frames = self.state.frame_rate.rate(),
+            )
+            .size(14)
+            .color(Color::WHITE),
+        )
+        .style(|_| container::Style {
+            background: Some(Background::Color(
+                Self::background_color(),
+            )),
+            ..Default::default()
+        })
+        .padding(4)
+        .into()
+    }
+}
+
 impl SolarSystem {
     /// Отображение времени
     fn time_panel(&self) -> Element<'_, Message> {
@@ -89,6 +126,16 @@ impl SolarSystem {
     }
 }
 
+impl SolarSystem {
+    /// Отображение зерна генератора случайных чисел
+    fn seed_panel(&self) -> Element<'_, Message> {
+        text!("Зерно: {seed}", seed = self.state.seed())
+            .size(14)
+            .color(Color::WHITE)
+            .into()
+    }
+}
+
 impl SolarSystem {
     /// Запуск/остановка симуляции
     fn play_toggle_panel(&self) -> Element<'_, Message> {
@@ -107,7 +154,7 @@ impl SolarSystem {
 }
 
 impl SolarSystem {
-    /// Изменение и отображение скорости
+    /// Изменение и отображение скорости и направления времени
     fn speed_panel(&self) -> Element<'_, Message> {
         let increase_speed_button: Element<_> = button("+")
             .width(40)
@@ -121,18 +168,27 @@ impl SolarSystem {
             .on_press(Message::DecreaseSpeed)
             .into();
 
-        let speed_value: Element<_> =
-            text(self.state.settings.speed().to_string())
-                .size(18)
-                .width(40)
-                .color(Color::WHITE)
-                .center()
-                .into();
+        let time_direction_button: Element<_> = button(
+            if self
+                .state
+                .settings
+                .time_direction()
+                .is_backward()
+            {
+                "<<"
+            } else {
+                ">>"
+            },
+        )
+        .width(40)
+        .height(40)
+        .on_press(Message::TimeDirectionToggle)
+        .into();
 
         row![
+            time_direction_button,
             decrease_speed_button,
-            increase_speed_button,
-            speed_value
+            increase_speed_button
         ]
         .spacing(4)
         .align_y(Vertical::Center)
@@ -140,6 +196,91 @@ impl SolarSystem {
     }
 }
 
+impl SolarSystem {
+    /// Плавное изменение скорости и отображение действительной скорости
+    fn speed_rate_panel(&self) -> Element<'_, Message> {
+        let speed = self.state.settings.speed();
+
+        // Ползунок в логарифмической шкале
+        let speed_slider: Element<_> = slider(
+            Speed::MINIMUM_DAYS_PER_SECOND.log10()
+                ..=Speed::MAXIMUM_DAYS_PER_SECOND.log10(),
+            speed.days_per_second().log10(),
+            |speed| Message::SpeedChange(10f64.powf(speed)),
+        )
+        .step(0.01)
+        .width(204)
+        .into();
+
+        let speed_value: Element<_> = text!(
+            "{speed} (факт: {effective:.2})",
+            effective =
+                self.state.effective_days_per_second
+        )
+        .size(14)
+        .color(Color::WHITE)
+        .into();
+
+        column![speed_value, speed_slider]
+            .align_x(Horizontal::Right)
+            .spacing(2)
+            .into()
+    }
+}
+
+impl SolarSystem {
+    /// Выбор точки, на которой центрируется камера
+    fn system_center_panel(&self) -> Element<'_, Message> {
+        button(
+            match self.state.system_position.system_center()
+            {
+                SystemCenter::Sun => "Центр: Солнце",
+                SystemCenter::Barycenter => {
+                    "Центр: барицентр"
+                }
+            },
+        )
+        .width(204)
+        .height(40)
+        .on_press(Message::SystemCenterToggle)
+        .into()
+    }
+}
+
+impl SolarSystem {
+    /// Выбор метода численного интегрирования
+    fn integration_method_panel(
+        &self,
+    ) -> Element<'_, Message> {
+        button(text!(
+            "Метод: {method}",
+            method = self
+                .state
+                .settings
+                .current_integration_method()
+        ))
+        .width(204)
+        .height(40)
+        .on_press(Message::ChangeIntegrationMethod)
+        .into()
+    }
+}
+
+impl SolarSystem {
+    /// Включение запуска комет оттягиванием мышью
+    fn launch_panel(&self) -> Element<'_, Message> {
+        button(if self.state.launch.is_enabled() {
+            "Запуск мышью: вкл"
+        } else {
+            "Запуск мышью: выкл"
+        })
+        .width(204)
+        .height(40)
+        .on_press(Message::LaunchModeToggle)
+        .into()
+    }
+}
+
 impl SolarSystem {
     /// Перезагрузка симуляции и центрирование системы на Солнце
     fn reload_and_center_panel(
@@ -162,6 +303,77 @@ impl SolarSystem {
     }
 }
 
+impl SolarSystem {
+    /// Сохранение и открытие снимка симуляции
+    fn snapshot_panel(&self) -> Element<'_, Message> {
+        let save_button: Element<_> = button("Сохранить")
+            .width(100)
+            .height(40)
+            .on_press(Message::SaveSnapshot)
+            .into();
+
+        let open_button: Element<_> = button("Открыть")
+            .width(100)
+            .height(40)
+            .on_press(Message::OpenSnapshot)
+            .into();
+
+        let buttons: Element<_> =
+            row![save_button, open_button]
+                .spacing(4)
+                .into();
+
+        match self.state.view.snapshot_status() {
+            Some(status) => column![
+                buttons,
+                text(status).size(14).color(Color::WHITE)
+            ]
+            .align_x(Horizontal::Right)
+            .spacing(4)
+            .into(),
+            None => buttons,
+        }
+    }
+}
+
+impl SolarSystem {
+    /// Перемотка назад по кадрам
+    fn rewind_panel(&self) -> Element<'_, Message> {
+        let timeline = &self.state.timeline;
+        let frames_count = timeline.frames_count;
+
+        if frames_count < 2 {
+            return Space::new().into();
+        }
+
+        let position = timeline.position;
+
+        let frame_time: Element<_> = text!(
+            "Перемотка: {time}",
+            time = timeline
+                .position_time
+                .as_ref()
+                .expect("Selected rewind frame must exist")
+        )
+        .size(14)
+        .color(Color::WHITE)
+        .into();
+
+        let timeline_slider: Element<_> = slider(
+            0..=(frames_count - 1) as u32,
+            position as u32,
+            Message::RewindTo,
+        )
+        .width(204)
+        .into();
+
+        column![frame_time, timeline_slider]
+            .align_x(Horizontal::Right)
+            .spacing(2)
+            .into()
+    }
+}
+
 impl SolarSystem {
     /// Фоновый цвет
     fn background_color() -> Color {
@@ -4,15 +4,26 @@ use iced::{
     Background, Border, Color, Element, Fill,
     alignment::Vertical,
     widget::{
-        Column, button, column, container,
-        container::Style, image, row, scrollable, text,
+        Column, Space, button, column, container,
+        container::Style, image, pick_list, row,
+        scrollable, text, text_input,
     },
 };
 
+use comet_movement_core::{
+    objects::{comet::Comet, planet::Planet},
+    util::objects::Object,
+};
+
 use crate::{
     Message, SolarSystem,
-    objects::{comet::Comet, planet::Planet},
-    util::objects::{Object, ObjectView},
+    objects::view::ObjectView,
+    state::{
+        comet_form::{
+            CometFormField, InitialConditionsInput,
+        },
+        view::SpawnProfileOption,
+    },
 };
 
 impl SolarSystem {
@@ -59,9 +70,20 @@ impl SolarSystem {
             .on_press(Message::AddComet)
             .into();
 
+        let comet_form_button: Element<_> =
+            button("Задать")
+                .width(70)
+                .height(Fill)
+                .on_press(Message::CometFormToggle)
+                .into();
+
         let comets_naming_table: Element<_> = container(
-            row![comets_naming, add_comet_button]
-                .spacing(1),
+            row![
+                comets_naming,
+                comet_form_button,
+                add_comet_button
+            ]
+            .spacing(1),
         )
         .style(|_| Self::container_background_style())
         .width(Fill)
@@ -73,26 +95,230 @@ impl SolarSystem {
         }
 
         let comets_view = Column::with_children(
-            self.state
-                .space
-                .comets()
-                .iter()
-                .enumerate()
-                .map(|(index, comet)| {
-                    self.comet_card(
-                        comet.clone(),
-                        index as u8,
-                    )
-                }),
+            self.state.space.comets().iter().map(|comet| {
+                self.comet_card(comet.clone())
+            }),
         );
 
         container(
-            column![comets_naming_table, comets_view]
-                .spacing(2),
+            column![
+                comets_naming_table,
+                self.spawn_profile_picker(),
+                self.spawn_status(),
+                self.comet_form(),
+                comets_view
+            ]
+            .spacing(2),
         )
         .width(Fill)
         .into()
     }
+
+    /// Выбор профиля появления новых комет
+    fn spawn_profile_picker(&self) -> Element<'_, Message> {
+        let spawn_profiles = self
+            .state
+            .space
+            .comet_possible_values()
+            .spawn_profiles();
+
+        if spawn_profiles.is_empty() {
+            return Space::new().into();
+        }
+
+        let options = [SpawnProfileOption {
+            index: None,
+            name: "Случайно".into(),
+        }]
+        .into_iter()
+        .chain(spawn_profiles.iter().enumerate().map(
+            |(index, profile)| SpawnProfileOption {
+                index: Some(index),
+                name: profile.name().clone(),
+            },
+        ))
+        .collect::<Vec<_>>();
+
+        let selected = options
+            .iter()
+            .find(|option| {
+                option.index
+                    == self.state.view.spawn_profile()
+            })
+            .cloned();
+
+        pick_list(
+            options,
+            selected,
+            Message::SpawnProfileSelect,
+        )
+        .width(Fill)
+        .into()
+    }
+
+    /// Причина, по которой не добавлена последняя комета
+    fn spawn_status(&self) -> Element<'_, Message> {
+        match self.state.view.spawn_status() {
+            Some(status) => text(status)
+                .size(14)
+                .color(Color::from_rgb8(255, 0, 0))
+                .into(),
+            None => Space::new().into(),
+        }
+    }
+
+    /// Форма с начальными условиями новой кометы
+    fn comet_form(&self) -> Element<'_, Message> {
+        let form = self.state.view.comet_form();
+
+        if !form.is_opened() {
+            return Space::new().into();
+        }
+
+        // Поля начальных условий с подписями и примерами
+        let initial_conditions = match form
+            .initial_conditions_input()
+        {
+            InitialConditionsInput::SpeedAndDirection => [
+                (CometFormField::X, ("x", "1 au")),
+                (CometFormField::Y, ("y", "0 km")),
+                (
+                    CometFormField::FirstVelocity,
+                    ("|v|", "30 km/s"),
+                ),
+                (
+                    CometFormField::SecondVelocity,
+                    ("Угол", "90 deg"),
+                ),
+            ],
+            InitialConditionsInput::Components => [
+                (CometFormField::X, ("x", "1 au")),
+                (CometFormField::Y, ("y", "0 km")),
+                (
+                    CometFormField::FirstVelocity,
+                    ("vx", "0 km/s"),
+                ),
+                (
+                    CometFormField::SecondVelocity,
+                    ("vy", "30 km/s"),
+                ),
+            ],
+            InitialConditionsInput::Perihelion => [
+                (
+                    CometFormField::PerihelionDistance,
+                    ("q", "0.5 au"),
+                ),
+                (
+                    CometFormField::Eccentricity,
+                    ("e", "0.99"),
+                ),
+                (
+                    CometFormField::PerihelionDirection,
+                    ("Перигелий", "45 deg"),
+                ),
+                (
+                    CometFormField::TimeToPerihelion,
+                    ("До перигелия", "100 d"),
+                ),
+            ],
+        };
+
+        let fields = Column::with_children(
+            initial_conditions
+                .into_iter()
+                .chain([
+                    (
+                        CometFormField::Mass,
+                        ("Масса", "1e13 kg"),
+                    ),
+                    (
+                        CometFormField::Radius,
+                        ("Радиус", "5 km"),
+                    ),
+                ])
+                .map(|(field, (label, placeholder))| {
+                    Self::comet_form_field(
+                        form.value(field),
+                        field,
+                        label,
+                        placeholder,
+                    )
+                }),
+        )
+        .spacing(2);
+
+        let initial_conditions_button: Element<_> =
+            button(match form.initial_conditions_input() {
+                InitialConditionsInput::SpeedAndDirection => {
+                    "Скорость: модуль и угол"
+                }
+                InitialConditionsInput::Components => {
+                    "Скорость: составляющие"
+                }
+                InitialConditionsInput::Perihelion => {
+                    "Орбита: перигелий"
+                }
+            })
+            .width(Fill)
+            .on_press(
+                Message::CometFormInitialConditionsToggle,
+            )
+            .into();
+
+        let submit_button: Element<_> = button("Добавить")
+            .width(Fill)
+            .on_press(Message::CometFormSubmit)
+            .into();
+
+        let mut form_view = column![
+            initial_conditions_button,
+            fields,
+            submit_button
+        ]
+        .spacing(4);
+
+        if let Some(error) = form.error() {
+            form_view = form_view.push(
+                text(error)
+                    .size(14)
+                    .color(Color::from_rgb8(255, 0, 0)),
+            );
+        }
+
+        container(form_view)
+            .style(|_| Self::container_background_style())
+            .padding(4)
+            .width(Fill)
+            .into()
+    }
+
+    /// Поле формы кометы с подписью
+    fn comet_form_field<'a>(
+        value: &'a str,
+        field: CometFormField,
+        label: &'a str,
+        placeholder: &'a str,
+    ) -> Element<'a, Message> {
+        let label: Element<_> = text(label)
+            .size(14)
+            .color(Color::WHITE)
+            .width(60)
+            .into();
+
+        let input: Element<_> =
+            text_input(placeholder, value)
+                .size(14)
+                .on_input(move |value| {
+                    Message::CometFormInput(field, value)
+                })
+                .on_submit(Message::CometFormSubmit)
+                .into();
+
+        row![label, input]
+            .align_y(Vertical::Center)
+            .spacing(2)
+            .into()
+    }
 }
 
 impl SolarSystem {
@@ -149,10 +375,10 @@ impl SolarSystem {
             return planet_card;
         }
 
-        let is_opened =
-            self.state.view.is_satellites_opened(
-                planet.borrow().name().to_string(),
-            );
+        let is_opened = self
+            .state
+            .view
+            .is_satellites_opened(planet.borrow().id());
 
         let satellites_naming: Element<_> = container(
             button(text(format!(
@@ -165,7 +391,7 @@ impl SolarSystem {
             .height(Fill)
             .on_press(
                 Message::SatellitesViewToggle(
-                    planet.borrow().name().to_string(),
+                    planet.borrow().id(),
                 ),
             ),
         )
@@ -234,15 +460,16 @@ impl SolarSystem {
     fn comet_card(
         &self,
         comet: Rc<RefCell<Comet>>,
-        comet_index: u8,
     ) -> Element<'_, Message> {
+        let comet_id = comet.borrow().id();
+
         let (image, name, velocity) =
             self.object_attributes(comet.clone());
 
         let remove_comet_button: Element<_> = button("-")
             .width(30)
             .height(30)
-            .on_press(Message::DeleteComet(comet_index))
+            .on_press(Message::DeleteComet(comet_id))
             .into();
 
         let comet_naming = row![name, remove_comet_button]
@@ -275,9 +502,11 @@ impl SolarSystem {
         let object = object.borrow();
 
         let image: Element<_> = container(Element::from(
-            image(object.image_view())
-                .height(100)
-                .width(100),
+            image(
+                self.state.images.get(object.image_view()),
+            )
+            .height(100)
+            .width(100),
         ))
         .padding(4)
         .center_y(Fill)